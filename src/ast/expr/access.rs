@@ -1,4 +1,4 @@
-use crate::util::span::Spanned;
+use crate::{declarations::scoped_item::ScopedItems, util::span::Spanned};
 
 use super::parser::Expression;
 
@@ -6,4 +6,45 @@ use super::parser::Expression;
 pub enum Access {
     Property(String),
     Index(Spanned<Expression>),
+    Graph(Graph),
+    /// `.*`, every field of an object or every item of an array.
+    All,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Direction {
+    Out,
+    In,
+    Both,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Direction::Out => write!(f, "->"),
+            Direction::In => write!(f, "<-"),
+            Direction::Both => write!(f, "<->"),
+        }
+    }
+}
+
+/// A single `->table`, `<-table` or `<->table` step of a graph traversal,
+/// optionally filtered with `[WHERE condition]`.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    pub direction: Direction,
+    pub table: Spanned<String>,
+    pub condition: Option<Spanned<Expression>>,
+}
+
+impl Graph {
+    /// The scope of the `[WHERE ...]` condition, where the fields of the
+    /// traversed table are in scope instead of the outer ones.
+    pub fn get_condition_scope(&self, scope: &ScopedItems) -> ScopedItems {
+        let mut scope = scope.clone();
+        if let Some(table) = scope.table_definitions.get(&self.table.0) {
+            scope.scoped_table = table.clone();
+        }
+        scope
+    }
 }
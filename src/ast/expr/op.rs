@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::declarations::type_::Type;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BinaryOperator {
     Add,
@@ -7,6 +9,7 @@ pub enum BinaryOperator {
     Multiply,
     Divide,
     Modulo,
+    Power,
     Equals,
     NotEquals,
     LessThan,
@@ -15,6 +18,31 @@ pub enum BinaryOperator {
     GreaterThanOrEqual,
     And,
     Or,
+    /// `??`, the right side when the left is `NONE` or `NULL`.
+    NullCoalesce,
+    /// `?:`, the right side when the left is falsy.
+    Truthy,
+    Contains,
+    ContainsNot,
+    ContainsAll,
+    ContainsAny,
+    ContainsNone,
+    /// `INSIDE` or `IN`.
+    Inside,
+    /// `NOTINSIDE` or `NOT IN`.
+    NotInside,
+    AllInside,
+    AnyInside,
+    NoneInside,
+    Outside,
+    Intersects,
+    /// `~`, a fuzzy string match.
+    Like,
+    NotLike,
+    /// `@@` or `@ref@`, a full-text search match.
+    Matches(Option<String>),
+    /// `<|k|>` or `<|k,distance|>`, the `k` nearest neighbours of a vector.
+    Knn(String),
 }
 
 impl Display for BinaryOperator {
@@ -25,6 +53,7 @@ impl Display for BinaryOperator {
             BinaryOperator::Add => "+",
             BinaryOperator::Subtract => "-",
             BinaryOperator::Modulo => "%",
+            BinaryOperator::Power => "**",
             BinaryOperator::Equals => "==",
             BinaryOperator::NotEquals => "!=",
             BinaryOperator::GreaterThan => ">",
@@ -33,7 +62,69 @@ impl Display for BinaryOperator {
             BinaryOperator::LessThanOrEqual => "<=",
             BinaryOperator::And => "&&",
             BinaryOperator::Or => "||",
+            BinaryOperator::NullCoalesce => "??",
+            BinaryOperator::Truthy => "?:",
+            BinaryOperator::Contains => "CONTAINS",
+            BinaryOperator::ContainsNot => "CONTAINSNOT",
+            BinaryOperator::ContainsAll => "CONTAINSALL",
+            BinaryOperator::ContainsAny => "CONTAINSANY",
+            BinaryOperator::ContainsNone => "CONTAINSNONE",
+            BinaryOperator::Inside => "INSIDE",
+            BinaryOperator::NotInside => "NOTINSIDE",
+            BinaryOperator::AllInside => "ALLINSIDE",
+            BinaryOperator::AnyInside => "ANYINSIDE",
+            BinaryOperator::NoneInside => "NONEINSIDE",
+            BinaryOperator::Outside => "OUTSIDE",
+            BinaryOperator::Intersects => "INTERSECTS",
+            BinaryOperator::Like => "~",
+            BinaryOperator::NotLike => "!~",
+            BinaryOperator::Matches(None) => "@@",
+            BinaryOperator::Matches(Some(reference)) => return write!(f, "@{}@", reference),
+            BinaryOperator::Knn(params) => return write!(f, "<|{}|>", params),
         };
         write!(f, "{}", s)
     }
 }
+
+impl BinaryOperator {
+    /// The type of `left op right`, or `Type::Error` if the operator can't
+    /// combine operands of these types.
+    pub fn get_result_type(&self, left: &Type, right: &Type) -> Type {
+        match self {
+            BinaryOperator::Add
+            | BinaryOperator::Subtract
+            | BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo
+            | BinaryOperator::Power => {
+                get_arithmetic_type(self, left.unwrap_option(), right.unwrap_option())
+            }
+            // These return one of their operands rather than a bool.
+            BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Truthy => {
+                left.get_shared_super_type(right)
+            }
+            BinaryOperator::NullCoalesce => left.unwrap_option().get_shared_super_type(right),
+            _ => Type::Bool,
+        }
+    }
+}
+
+fn get_arithmetic_type(op: &BinaryOperator, left: &Type, right: &Type) -> Type {
+    match (op, left, right) {
+        (_, Type::Error, _) | (_, _, Type::Error) => Type::Error,
+        (_, Type::Any, _) | (_, _, Type::Any) => Type::Any,
+        // Numbers are promoted along int, float, decimal and number.
+        (_, left, right) if left.is_numeric() && right.is_numeric() => {
+            left.get_shared_super_type(right)
+        }
+        (BinaryOperator::Add, Type::String, Type::String) => Type::String,
+        (BinaryOperator::Add, Type::Array(left), Type::Array(right)) => {
+            Type::Array(Box::new(left.get_shared_super_type(right)))
+        }
+        (BinaryOperator::Add | BinaryOperator::Subtract, Type::DateTime, Type::Duration)
+        | (BinaryOperator::Add, Type::Duration, Type::DateTime) => Type::DateTime,
+        (BinaryOperator::Add | BinaryOperator::Subtract, Type::Duration, Type::Duration)
+        | (BinaryOperator::Subtract, Type::DateTime, Type::DateTime) => Type::Duration,
+        _ => Type::Error,
+    }
+}
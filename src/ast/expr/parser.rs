@@ -1,7 +1,14 @@
-use crate::{ast::statement::statement::Statement, util::span::Spanned};
+use crate::{
+    ast::{statement::statement::Statement, type_::Type},
+    util::span::Spanned,
+};
 
 use super::{
-    access::Access, literal::Literal, object::ObjectEntry, op::BinaryOperator, unary::UnaryOperator,
+    access::{Access, Graph},
+    literal::Literal,
+    object::ObjectEntry,
+    op::BinaryOperator,
+    unary::UnaryOperator,
 };
 
 #[derive(Debug, Clone)]
@@ -17,6 +24,11 @@ pub enum Expression {
         op: Spanned<UnaryOperator>,
         expr: Box<Spanned<Expression>>,
     },
+    /// `<type> expr`, e.g. `<int> "42"` or `<future> { ... }`.
+    Cast {
+        ty: Spanned<Type>,
+        expr: Box<Spanned<Expression>>,
+    },
     Access {
         expr: Box<Spanned<Expression>>,
         access: Spanned<Box<Access>>,
@@ -30,4 +42,7 @@ pub enum Expression {
         name: Vec<Spanned<String>>,
         args: Option<Vec<Spanned<Expression>>>,
     },
+    Graph(Box<Graph>),
+    /// `*`, every field of the current record.
+    All,
 }
@@ -1,6 +1,14 @@
-use crate::declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type};
+use crate::{
+    ast::statement::{let_::LetStatement, statement::Statement},
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    ls::properties::parse_declared_type,
+};
 
-use super::{access::Access, literal::Literal, parser::Expression};
+use super::{
+    access::{Access, Graph},
+    literal::Literal,
+    parser::Expression,
+};
 
 pub trait Typed {
     fn get_type(&self, scope: &ScopedItems) -> Type;
@@ -41,30 +49,22 @@ impl Typed for Expression {
             Expression::Access { expr, access } => {
                 let ty = expr.0.get_type(scope);
                 match &access.0.as_ref() {
-                    Access::Property(name) => {
-                        let mut array_nest_count = 0;
-                        let mut ty = ty;
-                        while let Type::Array(inner_ty) = ty {
-                            ty = *inner_ty.clone();
-                            array_nest_count += 1;
-                        }
-                        if let Type::Object(obj) = ty {
-                            if let Some(field) = &obj.get_field(name) {
-                                let mut ty = field.ty.clone();
-                                for _ in 0..array_nest_count {
-                                    ty = Type::Array(Box::new(ty));
-                                }
-                                return ty;
-                            };
-                        };
-                        Type::Error
-                    }
+                    Access::Property(name) => map_records(ty, &|ty| match scope.dereference(ty) {
+                        Type::Object(obj) => match obj.get_field(name) {
+                            Some(field) => field.ty.clone(),
+                            None if obj.is_flexible => Type::Any,
+                            None => Type::Error,
+                        },
+                        _ => Type::Error,
+                    }),
                     Access::Index(_) => {
                         if let Type::Array(ty) = ty {
                             return *ty.clone();
                         };
                         Type::Error
                     }
+                    Access::Graph(graph) => graph.get_type(scope),
+                    Access::All => map_records(ty, &|ty| scope.dereference(ty)),
                 }
             }
             Expression::Object(object) => {
@@ -84,9 +84,15 @@ impl Typed for Expression {
                             Type::Option(_) => false,
                             _ => true,
                         },
+                        location: None,
+                        owner: None,
                     });
                 }
-                Type::Object(Object { fields })
+                Type::Object(Object {
+                    fields,
+                    location: None,
+                    is_flexible: false,
+                })
             }
             Expression::Array(array) => {
                 if array.is_empty() {
@@ -109,7 +115,7 @@ impl Typed for Expression {
                     if let Some(args) = args {
                         let arg_types = args
                             .iter()
-                            .map(|arg| arg.0.get_type(scope))
+                            .map(|arg| scope.get_arg_type(&name, arg.0.get_type(scope)))
                             .collect::<Vec<Type>>();
                         return def.get_return_type(arg_types);
                     };
@@ -119,7 +125,59 @@ impl Typed for Expression {
                 }
             }
             Expression::Inline(s) => s.as_ref().0.get_type(scope),
-            _ => Type::Error,
+            // A block returns the value of its last statement, which can use
+            // the variables declared before it.
+            Expression::CodeBlock(block) => {
+                let mut scope = scope.clone();
+                let mut ty = Type::Null;
+                for (statement, _) in block {
+                    if let Statement::Let(LetStatement {
+                        name: Some(name),
+                        value: Some(value),
+                    }) = statement
+                    {
+                        let value = value.0.get_type(&scope);
+                        scope.variables.insert(name.0.clone(), value);
+                    }
+                    ty = statement.get_type(&scope);
+                }
+                ty
+            }
+            Expression::Graph(graph) => graph.get_type(scope),
+            Expression::All => Type::Object(scope.scoped_table.clone()),
+            Expression::Binary { left, op, right } => {
+                op.0.get_result_type(&left.0.get_type(scope), &right.0.get_type(scope))
+            }
+            Expression::Cast { ty, expr } => match ty.0.name.0.as_str() {
+                // A future is computed whenever it is read, so it has the
+                // type of its value.
+                "future" => expr.0.get_type(scope),
+                _ => parse_declared_type(&ty.0),
+            },
+            Expression::Unary { op, expr } => op.0.get_result_type(&expr.0.get_type(scope)),
         }
     }
 }
+
+impl Typed for Graph {
+    fn get_type(&self, _scope: &ScopedItems) -> Type {
+        Type::Array(Box::new(Type::Record(self.table.0.clone())))
+    }
+}
+
+/// Applies `f` to the records or objects inside the arrays and options of
+/// `ty`, and wraps its result back up in them, so that the fields of an
+/// optional record are optional too.
+fn map_records(ty: Type, f: &dyn Fn(Type) -> Type) -> Type {
+    match ty {
+        Type::Array(inner) => match map_records(*inner, f) {
+            Type::Error => Type::Error,
+            ty => Type::Array(Box::new(ty)),
+        },
+        Type::Option(inner) => match map_records(*inner, f) {
+            ty @ (Type::Error | Type::Option(_)) => ty,
+            ty => Type::Option(Box::new(ty)),
+        },
+        ty => f(ty),
+    }
+}
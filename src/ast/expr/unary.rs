@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::declarations::type_::Type;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum UnaryOperator {
     Not,
@@ -15,3 +17,18 @@ impl Display for UnaryOperator {
         write!(f, "{}", s)
     }
 }
+
+impl UnaryOperator {
+    /// The type of the operator applied to an operand of type `ty`, or
+    /// `Type::Error` if it can't be.
+    pub fn get_result_type(&self, ty: &Type) -> Type {
+        match self {
+            UnaryOperator::Not => Type::Bool,
+            UnaryOperator::Negate => match ty.unwrap_option() {
+                Type::Error => Type::Error,
+                ty if ty.is_numeric() || matches!(ty, Type::Duration | Type::Any) => ty.clone(),
+                _ => Type::Error,
+            },
+        }
+    }
+}
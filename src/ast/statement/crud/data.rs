@@ -0,0 +1,37 @@
+use crate::{ast::expr::parser::Expression, util::span::Spanned};
+
+/// The data a statement writes to the records it creates or modifies.
+#[derive(Debug, Clone)]
+pub enum Data {
+    Content(Option<Spanned<Expression>>),
+    Replace(Option<Spanned<Expression>>),
+    Merge(Option<Spanned<Expression>>),
+    Patch(Option<Spanned<Expression>>),
+    Set(Vec<Spanned<Assignment>>),
+    Unset(Vec<Vec<Spanned<String>>>),
+}
+
+/// A `field.path = value` entry of a `SET` clause.
+#[derive(Debug, Clone)]
+pub struct Assignment {
+    pub field: Vec<Spanned<String>>,
+    pub op: AssignmentOperator,
+    pub value: Spanned<Expression>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssignmentOperator {
+    Assign,
+    Add,
+    Subtract,
+}
+
+impl std::fmt::Display for AssignmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AssignmentOperator::Assign => write!(f, "="),
+            AssignmentOperator::Add => write!(f, "+="),
+            AssignmentOperator::Subtract => write!(f, "-="),
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{ast::statement::transform::Transform, util::span::Spanned};
+use crate::{
+    ast::{statement::transform::Transform, target::Target},
+    util::span::Spanned,
+};
 
 #[derive(Debug, Clone)]
 pub struct DeleteStatement {
-    pub table: Option<Spanned<String>>,
+    pub target: Option<Spanned<Target>>,
     pub transforms: Vec<Spanned<Transform>>,
 }
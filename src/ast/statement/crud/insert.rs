@@ -0,0 +1,24 @@
+use crate::{ast::expr::parser::Expression, util::span::Spanned};
+
+use super::{data::Assignment, output::Output};
+
+#[derive(Debug, Clone)]
+pub struct InsertStatement {
+    pub ignore: bool,
+    pub table: Option<Spanned<String>>,
+    pub values: Option<Spanned<InsertValues>>,
+    pub on_duplicate: Option<Vec<Spanned<Assignment>>>,
+    pub output: Option<Spanned<Output>>,
+}
+
+/// The records an `INSERT` statement writes.
+#[derive(Debug, Clone)]
+pub enum InsertValues {
+    /// `(field, ...) VALUES (value, ...), ...`
+    Values {
+        fields: Vec<Spanned<String>>,
+        rows: Vec<Spanned<Vec<Spanned<Expression>>>>,
+    },
+    /// A single object, or an array of them for bulk inserts.
+    Content(Spanned<Expression>),
+}
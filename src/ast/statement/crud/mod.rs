@@ -1,4 +1,9 @@
 pub mod create;
+pub mod data;
 pub mod delete;
+pub mod insert;
+pub mod output;
+pub mod relate;
 pub mod select;
 pub mod update;
+pub mod upsert;
@@ -0,0 +1,11 @@
+use crate::{ast::projection::Projection, util::span::Spanned};
+
+/// The `RETURN` clause of a statement which writes records.
+#[derive(Debug, Clone)]
+pub enum Output {
+    None,
+    Before,
+    After,
+    Diff,
+    Fields(Vec<Spanned<Projection>>),
+}
@@ -0,0 +1,31 @@
+use crate::{
+    ast::target::Target,
+    declarations::{object::Object, scoped_item::ScopedItems},
+    util::span::Spanned,
+};
+
+use super::{data::Data, output::Output};
+
+#[derive(Debug, Clone)]
+pub struct RelateStatement {
+    pub from: Option<Spanned<Box<Target>>>,
+    pub edge: Option<Spanned<String>>,
+    pub to: Option<Spanned<Box<Target>>>,
+    pub data: Option<Spanned<Data>>,
+    pub output: Option<Spanned<Output>>,
+}
+
+impl RelateStatement {
+    /// The definition of the edge table, with `in` and `out` made optional as
+    /// they are filled in from the statement rather than its data.
+    pub fn get_edge_definition(&self, scope: &ScopedItems) -> Option<Object> {
+        let edge = self.edge.as_ref()?;
+        let mut obj = scope.table_definitions.get(&edge.0)?.clone();
+        for field in &mut obj.fields {
+            if field.name == "in" || field.name == "out" {
+                field.is_required = false;
+            }
+        }
+        Some(obj)
+    }
+}
@@ -1,11 +1,34 @@
 use crate::{
-    ast::{projection::Projection, statement::transform::Transform},
+    ast::{
+        expr::parser::Expression, projection::Projection, statement::transform::Transform,
+        target::Target,
+    },
     util::span::Spanned,
 };
 
 #[derive(Debug, Clone)]
 pub struct SelectStatement {
+    /// `SELECT VALUE`, which returns the values of the only projection rather
+    /// than objects holding them.
+    pub value: bool,
     pub projections: Vec<Spanned<Projection>>,
-    pub from: Option<Spanned<String>>,
+    /// The fields left out by `OMIT`.
+    pub omit: Vec<Spanned<Expression>>,
+    /// `FROM ONLY`, which returns a single record rather than an array.
+    pub only: bool,
+    pub from: Vec<Spanned<Target>>,
     pub transforms: Vec<Spanned<Transform>>,
 }
+
+impl SelectStatement {
+    /// The fields of the `GROUP` clause, which are empty for `GROUP ALL`, or
+    /// `None` if the records aren't grouped.
+    pub fn group(&self) -> Option<&Vec<Spanned<Expression>>> {
+        self.transforms
+            .iter()
+            .find_map(|transform| match &transform.0 {
+                Transform::Group(fields) => Some(fields),
+                _ => None,
+            })
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
-    ast::{expr::parser::Expression, statement::transform::Transform},
+    ast::{statement::transform::Transform, target::Target},
     util::span::Spanned,
 };
 
+use super::{data::Data, output::Output};
+
 #[derive(Debug, Clone)]
 pub struct UpdateStatement {
-    pub table: Option<Spanned<String>>,
-    pub content: Option<Spanned<Expression>>,
+    pub target: Option<Spanned<Target>>,
+    pub data: Option<Spanned<Data>>,
     pub transforms: Vec<Spanned<Transform>>,
+    pub output: Option<Spanned<Output>>,
 }
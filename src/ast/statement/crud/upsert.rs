@@ -0,0 +1,14 @@
+use crate::{
+    ast::{statement::transform::Transform, target::Target},
+    util::span::Spanned,
+};
+
+use super::{data::Data, output::Output};
+
+#[derive(Debug, Clone)]
+pub struct UpsertStatement {
+    pub target: Option<Spanned<Target>>,
+    pub data: Option<Spanned<Data>>,
+    pub transforms: Vec<Spanned<Transform>>,
+    pub output: Option<Spanned<Output>>,
+}
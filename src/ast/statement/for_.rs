@@ -0,0 +1,38 @@
+use crate::{
+    ast::expr::{parser::Expression, types::Typed},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    util::span::Spanned,
+};
+
+/// `FOR $item IN array { ... }`
+#[derive(Debug, Clone)]
+pub struct ForStatement {
+    pub variable: Spanned<String>,
+    pub iterable: Spanned<Expression>,
+    pub body: Spanned<Expression>,
+}
+
+impl ForStatement {
+    /// The type of the loop variable, an item of the iterated array.
+    pub fn get_item_type(&self, scope: &ScopedItems) -> Type {
+        match self.iterable.0.get_type(scope) {
+            Type::Array(inner) => *inner,
+            Type::Option(inner) => match *inner {
+                Type::Array(inner) => Type::Option(inner),
+                _ => Type::Any,
+            },
+            Type::Error => Type::Error,
+            _ => Type::Any,
+        }
+    }
+
+    /// `scope` as seen from inside the body, with the loop variable bound.
+    pub fn get_body_scope(&self, scope: &ScopedItems) -> ScopedItems {
+        let mut body_scope = scope.clone();
+        body_scope
+            .variables
+            .insert(self.variable.0.clone(), self.get_item_type(scope));
+        body_scope.is_in_loop = true;
+        body_scope
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{ast::expr::parser::Expression, util::span::Spanned};
+
+/// `IF cond { ... } ELSE IF cond { ... } ELSE { ... }`, or the same with
+/// `THEN` before each body and `END` after the last one.
+#[derive(Debug, Clone)]
+pub struct IfElseStatement {
+    /// Each condition, with the body which runs when it is the first to hold.
+    pub branches: Vec<(Spanned<Expression>, Spanned<Expression>)>,
+    pub otherwise: Option<Spanned<Expression>>,
+}
+
+impl IfElseStatement {
+    /// The bodies of every branch, including `ELSE`.
+    pub fn bodies(&self) -> Vec<&Spanned<Expression>> {
+        self.branches
+            .iter()
+            .map(|(_, body)| body)
+            .chain(&self.otherwise)
+            .collect()
+    }
+
+    /// Every condition and body, in the order they were written.
+    pub fn expressions(&self) -> Vec<&Spanned<Expression>> {
+        self.branches
+            .iter()
+            .flat_map(|(condition, body)| [condition, body])
+            .chain(&self.otherwise)
+            .collect()
+    }
+}
@@ -1,5 +1,7 @@
 pub mod crud;
 pub mod define;
+pub mod for_;
+pub mod if_else;
 pub mod let_;
 pub mod statement;
 pub mod transform;
@@ -2,10 +2,13 @@ use crate::{ast::expr::parser::Expression, util::span::Spanned};
 
 use super::{
     crud::{
-        create::CreateStatement, delete::DeleteStatement, select::SelectStatement,
-        update::UpdateStatement,
+        create::CreateStatement, delete::DeleteStatement, insert::InsertStatement,
+        relate::RelateStatement, select::SelectStatement, update::UpdateStatement,
+        upsert::UpsertStatement,
     },
     define::DefineStatement,
+    for_::ForStatement,
+    if_else::IfElseStatement,
     let_::LetStatement,
 };
 
@@ -14,9 +17,19 @@ pub enum Statement {
     Select(SelectStatement),
     Create(CreateStatement),
     Update(UpdateStatement),
+    Upsert(UpsertStatement),
     Delete(DeleteStatement),
+    Insert(InsertStatement),
+    Relate(RelateStatement),
     Return(Spanned<Expression>),
     Define(Spanned<DefineStatement>),
     Let(LetStatement),
+    IfElse(IfElseStatement),
+    For(ForStatement),
+    Break,
+    Continue,
+    Throw(Spanned<Expression>),
+    /// A bare value, which a block returns when it is its last statement.
+    Expression(Spanned<Expression>),
     Invalid,
 }
@@ -5,7 +5,42 @@ use crate::{
 #[derive(Debug, Clone)]
 pub enum Transform {
     Where(Option<Spanned<Expression>>),
+    /// `SPLIT [ON] fields`, which unwinds array fields into one record per
+    /// item.
+    Split(Vec<Spanned<Expression>>),
+    /// `GROUP [BY] fields`. `GROUP ALL` has no fields.
+    Group(Vec<Spanned<Expression>>),
+    /// `ORDER [BY] field [COLLATE] [NUMERIC] [ASC|DESC], ...`
+    Order(Vec<Spanned<Expression>>),
     Limit(Option<Spanned<Expression>>),
+    /// `START [AT] n`, or `SKIP n`.
     Skip(Option<Spanned<Expression>>),
+    /// `FETCH fields`, which replaces record links with the records.
+    Fetch(Vec<Spanned<Expression>>),
+    Timeout(Option<Spanned<Expression>>),
+    /// `WITH INDEX indexes` or `WITH NOINDEX`.
+    With,
+    Parallel,
+    /// `EXPLAIN [FULL]`, which returns the query plan instead of the records.
+    Explain,
     Invalid(Unexpected),
 }
+
+impl Transform {
+    /// The expressions the clause is made of.
+    pub fn expressions(&self) -> Vec<&Spanned<Expression>> {
+        match self {
+            Transform::Where(expr)
+            | Transform::Limit(expr)
+            | Transform::Skip(expr)
+            | Transform::Timeout(expr) => expr.iter().collect(),
+            Transform::Split(exprs)
+            | Transform::Group(exprs)
+            | Transform::Order(exprs)
+            | Transform::Fetch(exprs) => exprs.iter().collect(),
+            Transform::With | Transform::Parallel | Transform::Explain | Transform::Invalid(_) => {
+                vec![]
+            }
+        }
+    }
+}
@@ -1,7 +1,120 @@
-use crate::util::span::Spanned;
+use std::fmt::Display;
 
+use crate::{
+    declarations::{object::Object, scoped_item::ScopedItems, type_::Type},
+    util::span::Spanned,
+};
+
+use super::expr::{parser::Expression, types::Typed};
+
+/// What a statement reads from or writes to.
+#[derive(Debug, Clone)]
 pub enum Target {
+    /// Every record in a table, e.g. `person`.
     Table(Spanned<String>),
-    Record(Spanned<String>, Spanned<String>),
+    /// A single record, e.g. `person:tobie`.
+    Record(Spanned<String>, Spanned<RecordKey>),
+    /// The records of a table whose ids lie in a range, e.g. `person:1..100`.
+    /// Either end can be left open.
+    Range(
+        Spanned<String>,
+        Option<Spanned<RecordKey>>,
+        Option<Spanned<RecordKey>>,
+    ),
+    /// A `$variable` or a subquery holding the records.
+    Expression(Spanned<Expression>),
+}
+
+/// The part of a record id after the table name.
+#[derive(Debug, Clone)]
+pub enum RecordKey {
+    /// `tobie`, `1` or `⟨complex id⟩`.
+    Id(String),
+    /// An array or object id, e.g. `['London', d"2022-08-29T08:03:39"]`.
+    Value(Spanned<Expression>),
 }
 
+impl Target {
+    /// The table the target names, if it names one directly.
+    pub fn table(&self) -> Option<&Spanned<String>> {
+        match self {
+            Target::Table(table) | Target::Record(table, _) | Target::Range(table, _, _) => {
+                Some(table)
+            }
+            Target::Expression(_) => None,
+        }
+    }
+
+    /// The expressions inside the target: the values of array and object ids,
+    /// or the variable or subquery itself.
+    pub fn expressions(&self) -> Vec<&Spanned<Expression>> {
+        let keys = match self {
+            Target::Table(_) => vec![],
+            Target::Record(_, key) => vec![key],
+            Target::Range(_, start, end) => start.iter().chain(end).collect(),
+            Target::Expression(expr) => return vec![expr],
+        };
+        keys.into_iter()
+            .filter_map(|key| match &key.0 {
+                RecordKey::Value(value) => Some(value),
+                RecordKey::Id(_) => None,
+            })
+            .collect()
+    }
+
+    /// The definition of the table the target's records belong to. For
+    /// variables and subqueries, this follows the type of the expression.
+    pub fn get_definition(&self, scope: &ScopedItems) -> Option<Object> {
+        match self.table() {
+            Some(table) => scope.table_definitions.get(&table.0).cloned(),
+            None => match self {
+                Target::Expression(expr) => get_record_definition(expr.0.get_type(scope), scope),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn get_record_definition(ty: Type, scope: &ScopedItems) -> Option<Object> {
+    match ty {
+        Type::Array(inner) | Type::Option(inner) => get_record_definition(*inner, scope),
+        Type::Record(table) => scope.table_definitions.get(&table).cloned(),
+        Type::Object(obj) => Some(obj),
+        _ => None,
+    }
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Table(table) => write!(f, "{}", table.0),
+            Target::Record(table, key) => write!(f, "{}:{}", table.0, key.0),
+            Target::Range(table, start, end) => {
+                write!(f, "{}:", table.0)?;
+                if let Some(start) = start {
+                    write!(f, "{}", start.0)?;
+                }
+                write!(f, "..")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end.0)?;
+                }
+                Ok(())
+            }
+            Target::Expression((Expression::Variable(name), _)) => write!(f, "${}", name),
+            Target::Expression(_) => write!(f, "(...)"),
+        }
+    }
+}
+
+impl Display for RecordKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordKey::Id(id) if id.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                write!(f, "{}", id)
+            }
+            RecordKey::Id(id) => write!(f, "⟨{}⟩", id),
+            RecordKey::Value((Expression::Array(_), _)) => write!(f, "[...]"),
+            RecordKey::Value(_) => write!(f, "{{...}}"),
+        }
+    }
+}
@@ -18,7 +18,7 @@ pub fn lex(file: PathBuf) {
                         start: prev,
                         end: span.start,
                     };
-                    let text = rope.get_slice(range).unwrap();
+                    let text = rope.get_byte_slice(range).unwrap();
                     print!("{}", text);
                 }
                 prev = span.end;
@@ -37,10 +37,11 @@ pub fn lex(file: PathBuf) {
                     Token::Boolean(_) => "yellow",
                     Token::Variable(_) => "white",
                     Token::Newline => "white",
+                    Token::Comment(_) => "bright black",
                 };
                 let color = colored::Color::from(color);
                 let mut text = rope
-                    .get_slice(Range {
+                    .get_byte_slice(Range {
                         start: span.start,
                         end: span.end,
                     })
@@ -1,8 +1,24 @@
+use tower_lsp::lsp_types::Location;
+
 use super::type_::Type;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct Field {
     pub name: String,
     pub ty: Type,
     pub is_required: bool,
+    /// Where the field is declared by a `DEFINE FIELD` statement, if known.
+    pub location: Option<Location>,
+    /// The table the field is defined on and the fields it is nested in, e.g.
+    /// `person` and `["address"]` for `address.city`. This tells fields of the
+    /// same name apart when their `DEFINE FIELD` statements aren't known.
+    pub owner: Option<(String, Vec<String>)>,
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.ty == other.ty && self.is_required == other.is_required
+    }
 }
+
+impl Eq for Field {}
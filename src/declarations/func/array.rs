@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{
+    arg, array_of, generic_arg, generic_array, generic_option, insert_function, option_of,
+    optional_arg, rest_arg, type_param,
+};
+
+pub fn get_array_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "array::add",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        generic_array(),
+        "Adds a value to an array, if it is not already present.",
+        "RETURN array::add([\"one\", \"two\"], \"three\");\n\n[\"one\", \"two\", \"three\"]",
+    );
+    insert_function(
+        &mut map,
+        "array::all",
+        vec![arg("array", array_of(Type::Any))],
+        GenericType::Named(Type::Bool),
+        "Checks whether all of the items in an array are truthy.",
+        "RETURN array::all([1, 2, 3, NONE, 'SurrealDB', 5]);\n\nfalse",
+    );
+    insert_function(
+        &mut map,
+        "array::any",
+        vec![arg("array", array_of(Type::Any))],
+        GenericType::Named(Type::Bool),
+        "Checks whether any of the items in an array are truthy.",
+        "RETURN array::any([1, 2, 3, NONE, 'SurrealDB', 5]);\n\ntrue",
+    );
+    insert_function(
+        &mut map,
+        "array::at",
+        vec![
+            generic_arg("array", generic_array()),
+            arg("index", Type::Int),
+        ],
+        type_param(),
+        "Returns the item at an index, counting from the end when the index is negative.",
+        "RETURN array::at(['s', 'u', 'r', 'r', 'e', 'a', 'l'], -3);\n\n'e'",
+    );
+    insert_function(
+        &mut map,
+        "array::append",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        generic_array(),
+        "Appends a value to the end of an array.",
+        "RETURN array::append([1, 2, 3, 4], 5);\n\n[1, 2, 3, 4, 5]",
+    );
+    for (name, description, example) in [
+        (
+            "boolean_and",
+            "Performs the AND bitwise operation on the items of two arrays.",
+            "RETURN array::boolean_and([false, true, false, true], [false, false, true, true]);\n\n[false, false, false, true]",
+        ),
+        (
+            "boolean_or",
+            "Performs the OR bitwise operation on the items of two arrays.",
+            "RETURN array::boolean_or([false, true, false, true], [false, false, true, true]);\n\n[false, true, true, true]",
+        ),
+        (
+            "boolean_xor",
+            "Performs the XOR bitwise operation on the items of two arrays.",
+            "RETURN array::boolean_xor([false, true, false, true], [false, false, true, true]);\n\n[false, true, true, false]",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("array::{}", name),
+            vec![
+                arg("lhs", array_of(Type::Any)),
+                arg("rhs", array_of(Type::Any)),
+            ],
+            GenericType::Named(array_of(Type::Bool)),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "array::boolean_not",
+        vec![arg("array", array_of(Type::Any))],
+        GenericType::Named(array_of(Type::Bool)),
+        "Performs the NOT bitwise operation on the items of an array.",
+        "RETURN array::boolean_not([false, true, 0, 1]);\n\n[true, false, true, false]",
+    );
+    insert_function(
+        &mut map,
+        "array::clump",
+        vec![
+            generic_arg("array", generic_array()),
+            arg("size", Type::Int),
+        ],
+        GenericType::Named(array_of(array_of(Type::Any))),
+        "Splits an array into arrays of the given size.",
+        "RETURN array::clump([0, 1, 2, 3], 2);\n\n[[0, 1], [2, 3]]",
+    );
+    for (name, description, example) in [
+        (
+            "combine",
+            "Combines all items of two arrays into pairs.",
+            "RETURN array::combine([1, 2], [2, 3]);\n\n[[1, 2], [1, 3], [2, 2], [2, 3]]",
+        ),
+        (
+            "complement",
+            "Returns the items of the first array which are not in the second.",
+            "RETURN array::complement([1, 2, 3, 4], [3, 4, 5, 6]);\n\n[1, 2]",
+        ),
+        (
+            "concat",
+            "Merges arrays together, keeping duplicate values.",
+            "RETURN array::concat([1, 2, 3, 4], [3, 4, 5, 6]);\n\n[1, 2, 3, 4, 3, 4, 5, 6]",
+        ),
+        (
+            "difference",
+            "Returns the items which are only in one of the two arrays.",
+            "RETURN array::difference([1, 2, 3, 4], [3, 4, 5, 6]);\n\n[1, 2, 5, 6]",
+        ),
+        (
+            "intersect",
+            "Returns the items which are in both arrays.",
+            "RETURN array::intersect([1, 2, 3, 4], [3, 4, 5, 6]);\n\n[3, 4]",
+        ),
+        (
+            "union",
+            "Merges two arrays together, removing duplicate values.",
+            "RETURN array::union([1, 2, 1, 6], [1, 3, 4, 5, 6]);\n\n[1, 2, 6, 3, 4, 5]",
+        ),
+    ] {
+        let return_type = if name == "combine" {
+            GenericType::Named(array_of(array_of(Type::Any)))
+        } else {
+            generic_array()
+        };
+        let args = if name == "concat" {
+            vec![
+                generic_arg("array", generic_array()),
+                rest_arg("arrays", generic_array()),
+            ]
+        } else {
+            vec![
+                generic_arg("lhs", generic_array()),
+                generic_arg("rhs", generic_array()),
+            ]
+        };
+        insert_function(
+            &mut map,
+            &format!("array::{}", name),
+            args,
+            return_type,
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "array::distinct",
+        vec![generic_arg("array", generic_array())],
+        generic_array(),
+        "Removes duplicate values from an array.",
+        "RETURN array::distinct([1, 2, 1, 3, 3, 4]);\n\n[1, 2, 3, 4]",
+    );
+    insert_function(
+        &mut map,
+        "array::filter_index",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        GenericType::Named(array_of(Type::Int)),
+        "Returns the indexes of every item in an array which matches a value.",
+        "RETURN array::filter_index(['a', 'b', 'c', 'b', 'a'], 'b');\n\n[1, 3]",
+    );
+    insert_function(
+        &mut map,
+        "array::find_index",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        GenericType::Named(option_of(Type::Int)),
+        "Returns the index of the first item in an array which matches a value.",
+        "RETURN array::find_index(['a', 'b', 'c', 'b', 'a'], 'b');\n\n1",
+    );
+    insert_function(
+        &mut map,
+        "array::first",
+        vec![generic_arg("array", generic_array())],
+        generic_option(),
+        "Returns the first item in an array.",
+        "RETURN array::first(['s', 'u', 'r', 'r', 'e', 'a', 'l']);\n\n's'",
+    );
+    insert_function(
+        &mut map,
+        "array::flatten",
+        vec![arg("array", array_of(array_of(Type::Any)))],
+        GenericType::Named(array_of(Type::Any)),
+        "Flattens an array of arrays into a single array.",
+        "RETURN array::flatten([[1, 2], [3, 4], 'SurrealDB', [5, 6, [7, 8]]]);\n\n[1, 2, 3, 4, 'SurrealDB', 5, 6, [7, 8]]",
+    );
+    insert_function(
+        &mut map,
+        "array::group",
+        vec![arg("array", array_of(Type::Any))],
+        GenericType::Named(array_of(Type::Any)),
+        "Flattens and returns the unique items in an array.",
+        "RETURN array::group([1, 2, 3, 4, [3, 5, 6], [2, 4, 5, 6], 7, 8, 8, 9]);\n\n[1, 2, 3, 4, 5, 6, 7, 8, 9]",
+    );
+    insert_function(
+        &mut map,
+        "array::insert",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+            optional_arg("index", Type::Int),
+        ],
+        generic_array(),
+        "Inserts a value into an array at an index.",
+        "RETURN array::insert([1, 2, 3, 4], 5, 2);\n\n[1, 2, 5, 3, 4]",
+    );
+    insert_function(
+        &mut map,
+        "array::join",
+        vec![
+            arg("array", array_of(Type::Any)),
+            arg("separator", Type::String),
+        ],
+        GenericType::Named(Type::String),
+        "Joins the items of an array into a string, separated by a delimiter.",
+        "RETURN array::join(['again', 'again', 'again'], ' and ');\n\n'again and again and again'",
+    );
+    insert_function(
+        &mut map,
+        "array::last",
+        vec![generic_arg("array", generic_array())],
+        generic_option(),
+        "Returns the last item in an array.",
+        "RETURN array::last(['s', 'u', 'r', 'r', 'e', 'a', 'l']);\n\n'l'",
+    );
+    insert_function(
+        &mut map,
+        "array::len",
+        vec![arg("array", array_of(Type::Any))],
+        GenericType::Named(Type::Int),
+        "Returns the length of an array.",
+        "RETURN array::len([1, 2, 1, null, 'something', 3, 3, 4, 0]);\n\n9",
+    );
+    for (name, description, example) in [
+        (
+            "logical_and",
+            "Performs the AND logical operation on the items of two arrays.",
+            "RETURN array::logical_and([true, false, true, false], [true, true, false, false]);\n\n[true, false, false, false]",
+        ),
+        (
+            "logical_or",
+            "Performs the OR logical operation on the items of two arrays.",
+            "RETURN array::logical_or([true, false, true, false], [true, true, false, false]);\n\n[true, true, true, false]",
+        ),
+        (
+            "logical_xor",
+            "Performs the XOR logical operation on the items of two arrays.",
+            "RETURN array::logical_xor([true, false, true, false], [true, true, false, false]);\n\n[false, true, true, false]",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("array::{}", name),
+            vec![
+                arg("lhs", array_of(Type::Any)),
+                arg("rhs", array_of(Type::Any)),
+            ],
+            GenericType::Named(array_of(Type::Any)),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "array::matches",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        GenericType::Named(array_of(Type::Bool)),
+        "Returns whether each item in an array matches a value.",
+        "RETURN array::matches([0, 1, 2], 1);\n\n[false, true, false]",
+    );
+    insert_function(
+        &mut map,
+        "array::max",
+        vec![generic_arg("array", generic_array())],
+        type_param(),
+        "Returns the greatest item in an array.",
+        "RETURN array::max([0, 1, 2]);\n\n2",
+    );
+    insert_function(
+        &mut map,
+        "array::min",
+        vec![generic_arg("array", generic_array())],
+        type_param(),
+        "Returns the least item in an array.",
+        "RETURN array::min([0, 1, 2]);\n\n0",
+    );
+    insert_function(
+        &mut map,
+        "array::pop",
+        vec![generic_arg("array", generic_array())],
+        generic_option(),
+        "Returns the last item of an array, removing it from the array.",
+        "RETURN array::pop([1, 2, 3, 4]);\n\n4",
+    );
+    insert_function(
+        &mut map,
+        "array::prepend",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        generic_array(),
+        "Prepends a value to the start of an array.",
+        "RETURN array::prepend([1, 2, 3, 4], 5);\n\n[5, 1, 2, 3, 4]",
+    );
+    insert_function(
+        &mut map,
+        "array::push",
+        vec![
+            generic_arg("array", generic_array()),
+            generic_arg("value", type_param()),
+        ],
+        generic_array(),
+        "Appends a value to the end of an array.",
+        "RETURN array::push([1, 2, 3, 4], 5);\n\n[1, 2, 3, 4, 5]",
+    );
+    insert_function(
+        &mut map,
+        "array::remove",
+        vec![
+            generic_arg("array", generic_array()),
+            arg("index", Type::Int),
+        ],
+        generic_array(),
+        "Removes the item at an index from an array.",
+        "RETURN array::remove([1, 2, 1, 3, 3, 4], 0);\n\n[2, 1, 3, 3, 4]",
+    );
+    insert_function(
+        &mut map,
+        "array::reverse",
+        vec![generic_arg("array", generic_array())],
+        generic_array(),
+        "Reverses the order of an array.",
+        "RETURN array::reverse([1, 2, 3, 4]);\n\n[4, 3, 2, 1]",
+    );
+    insert_function(
+        &mut map,
+        "array::slice",
+        vec![
+            generic_arg("array", generic_array()),
+            optional_arg("start", Type::Int),
+            optional_arg("length", Type::Int),
+        ],
+        generic_array(),
+        "Returns a slice of an array, from a start index and of a given length.",
+        "RETURN array::slice([1, 2, 3, 4, 5], 1, 2);\n\n[2, 3]",
+    );
+    insert_function(
+        &mut map,
+        "array::sort",
+        vec![
+            generic_arg("array", generic_array()),
+            optional_arg("order", Type::Any),
+        ],
+        generic_array(),
+        "Sorts the items of an array, in ascending order unless `order` is `false` or 'desc'.",
+        "RETURN array::sort([1, 2, 1, null, 'something', 3, 3, 4, 0]);\n\n[null, 0, 1, 1, 2, 3, 3, 4, 'something']",
+    );
+    insert_function(
+        &mut map,
+        "array::sort::asc",
+        vec![generic_arg("array", generic_array())],
+        generic_array(),
+        "Sorts the items of an array, in ascending order unless `order` is `false` or 'desc'.",
+        "RETURN array::sort::asc([2, 1, 3]);\n\n[1, 2, 3]",
+    );
+    insert_function(
+        &mut map,
+        "array::sort::desc",
+        vec![generic_arg("array", generic_array())],
+        generic_array(),
+        "Sorts the items of an array in descending order.",
+        "RETURN array::sort::desc([2, 1, 3]);\n\n[3, 2, 1]",
+    );
+    insert_function(
+        &mut map,
+        "array::transpose",
+        vec![arg("array", array_of(array_of(Type::Any)))],
+        GenericType::Named(array_of(array_of(Type::Any))),
+        "Swaps the rows and columns of an array of arrays.",
+        "RETURN array::transpose([[0, 1], [2, 3]]);\n\n[[0, 2], [1, 3]]",
+    );
+
+    map
+}
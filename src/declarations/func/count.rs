@@ -85,7 +85,7 @@
 use std::collections::HashMap;
 
 use crate::declarations::{
-    functions::{Function, GenericType},
+    functions::{ArgKind, Function, FunctionArg, GenericType, Signature},
     type_::Type,
 };
 
@@ -95,8 +95,28 @@ pub fn get_count_functions() -> HashMap<String, Function> {
     map.insert(
         "count".to_string(),
         Function {
-            args: vec![],
-            return_type: GenericType::Named(Type::Int),
+            overloads: vec![
+                Signature {
+                    args: vec![],
+                    return_type: GenericType::Named(Type::Int),
+                },
+                Signature {
+                    args: vec![FunctionArg(
+                        "values".to_string(),
+                        GenericType::Named(Type::Array(Box::new(Type::Any))),
+                        ArgKind::Required,
+                    )],
+                    return_type: GenericType::Named(Type::Int),
+                },
+                Signature {
+                    args: vec![FunctionArg(
+                        "value".to_string(),
+                        GenericType::Named(Type::Any),
+                        ArgKind::Required,
+                    )],
+                    return_type: GenericType::Named(Type::Int),
+                },
+            ],
             doc: Some(r#"
             ## Count
             The count function counts the number of times that the function is called. This is useful for returning the total number of rows in a SELECT statement with a `GROUP BY` clause.
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_crypto_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for name in ["md5", "sha1", "sha256", "sha512"] {
+        insert_function(
+            &mut map,
+            &format!("crypto::{}", name),
+            vec![arg("value", Type::String)],
+            GenericType::Named(Type::String),
+            &format!("Returns the {} hash of a value.", name),
+            &format!("RETURN crypto::{}('tobie');", name),
+        );
+    }
+    for name in ["argon2", "bcrypt", "pbkdf2", "scrypt"] {
+        insert_function(
+            &mut map,
+            &format!("crypto::{}::compare", name),
+            vec![arg("hash", Type::String), arg("password", Type::String)],
+            GenericType::Named(Type::Bool),
+            &format!("Compares a password hashed with {} to an unhashed password.", name),
+            &format!(
+                "LET $hash = crypto::{}::generate('this is a strong password');\nRETURN crypto::{}::compare($hash, 'this is a strong password');\n\ntrue",
+                name, name
+            ),
+        );
+        insert_function(
+            &mut map,
+            &format!("crypto::{}::generate", name),
+            vec![arg("password", Type::String)],
+            GenericType::Named(Type::String),
+            &format!("Hashes a password with {}.", name),
+            &format!(
+                "RETURN crypto::{}::generate('this is a strong password');",
+                name
+            ),
+        );
+    }
+
+    map
+}
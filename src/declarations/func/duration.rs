@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_duration_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, unit, example) in [
+        ("days", "days", "RETURN duration::days(3w);\n\n21"),
+        ("hours", "hours", "RETURN duration::hours(3w);\n\n504"),
+        (
+            "micros",
+            "microseconds",
+            "RETURN duration::micros(3w);\n\n1814400000000",
+        ),
+        (
+            "millis",
+            "milliseconds",
+            "RETURN duration::millis(3w);\n\n1814400000",
+        ),
+        ("mins", "minutes", "RETURN duration::mins(3w);\n\n30240"),
+        (
+            "nanos",
+            "nanoseconds",
+            "RETURN duration::nanos(3w);\n\n1814400000000000",
+        ),
+        ("secs", "seconds", "RETURN duration::secs(3w);\n\n1814400"),
+        ("weeks", "weeks", "RETURN duration::weeks(3w);\n\n3"),
+        ("years", "years", "RETURN duration::years(300w);\n\n5"),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("duration::{}", name),
+            vec![arg("duration", Type::Duration)],
+            GenericType::Named(Type::Int),
+            &format!("Counts how many whole {} fit in a duration.", unit),
+            example,
+        );
+    }
+    for (name, unit, example) in [
+        ("days", "days", "RETURN duration::from::days(3);\n\n3d"),
+        ("hours", "hours", "RETURN duration::from::hours(3);\n\n3h"),
+        (
+            "micros",
+            "microseconds",
+            "RETURN duration::from::micros(3);\n\n3µs",
+        ),
+        (
+            "millis",
+            "milliseconds",
+            "RETURN duration::from::millis(3);\n\n3ms",
+        ),
+        ("mins", "minutes", "RETURN duration::from::mins(3);\n\n3m"),
+        (
+            "nanos",
+            "nanoseconds",
+            "RETURN duration::from::nanos(3);\n\n3ns",
+        ),
+        ("secs", "seconds", "RETURN duration::from::secs(3);\n\n3s"),
+        ("weeks", "weeks", "RETURN duration::from::weeks(3);\n\n3w"),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("duration::from::{}", name),
+            vec![arg(unit, Type::Int)],
+            GenericType::Named(Type::Duration),
+            &format!("Converts a number of {} into a duration.", unit),
+            example,
+        );
+    }
+
+    map
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function, optional_arg};
+
+// Geometries are not modelled by `Type` yet, so they are typed as `any`.
+pub fn get_geo_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "geo::area",
+        vec![arg("geometry", Type::Any)],
+        GenericType::Named(Type::Float),
+        "Calculates the area of a geometry in square metres.",
+        "RETURN geo::area({\n    type: 'Polygon',\n    coordinates: [[\n        [-0.38314819, 51.37692386], [0.1785278, 51.37692386],\n        [0.1785278, 51.61460570], [-0.38314819, 51.61460570],\n        [-0.38314819, 51.37692386]\n    ]]\n});",
+    );
+    insert_function(
+        &mut map,
+        "geo::bearing",
+        vec![arg("from", Type::Any), arg("to", Type::Any)],
+        GenericType::Named(Type::Float),
+        "Calculates the bearing between two points.",
+        "RETURN geo::bearing((51.509865, -0.118092), (-0.118092, 51.509865));",
+    );
+    insert_function(
+        &mut map,
+        "geo::centroid",
+        vec![arg("geometry", Type::Any)],
+        GenericType::Named(Type::Any),
+        "Calculates the centroid of a geometry.",
+        "RETURN geo::centroid({\n    type: 'Polygon',\n    coordinates: [[\n        [-0.03921, 51.50502], [-0.03921, 51.50519],\n        [-0.03893, 51.50519], [-0.03921, 51.50502]\n    ]]\n});",
+    );
+    insert_function(
+        &mut map,
+        "geo::distance",
+        vec![arg("from", Type::Any), arg("to", Type::Any)],
+        GenericType::Named(Type::Float),
+        "Calculates the great-circle distance between two points, in metres.",
+        "RETURN geo::distance((-0.04592, 51.55655), (30.46, -17.86));",
+    );
+    insert_function(
+        &mut map,
+        "geo::hash::decode",
+        vec![arg("hash", Type::String)],
+        GenericType::Named(Type::Any),
+        "Converts a geohash into a point.",
+        "RETURN geo::hash::decode('mpuxk4s24f51');",
+    );
+    insert_function(
+        &mut map,
+        "geo::hash::encode",
+        vec![
+            arg("point", Type::Any),
+            optional_arg("precision", Type::Int),
+        ],
+        GenericType::Named(Type::String),
+        "Converts a point into a geohash.",
+        "RETURN geo::hash::encode((51.509865, -0.118092));",
+    );
+
+    map
+}
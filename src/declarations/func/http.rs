@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{any_object, arg, insert_function, optional_arg};
+
+pub fn get_http_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "http::head",
+        vec![
+            arg("url", Type::String),
+            optional_arg("headers", any_object()),
+        ],
+        GenericType::Named(Type::Null),
+        "Performs a remote HTTP HEAD request.",
+        "RETURN http::head('https://surrealdb.com');\n\nnull",
+    );
+    for (name, method) in [("get", "GET"), ("delete", "DELETE")] {
+        insert_function(
+            &mut map,
+            &format!("http::{}", name),
+            vec![
+                arg("url", Type::String),
+                optional_arg("headers", any_object()),
+            ],
+            GenericType::Named(Type::Any),
+            &format!("Performs a remote HTTP {} request.", method),
+            &format!("RETURN http::{}('https://surrealdb.com');", name),
+        );
+    }
+    for (name, method) in [("put", "PUT"), ("post", "POST"), ("patch", "PATCH")] {
+        insert_function(
+            &mut map,
+            &format!("http::{}", name),
+            vec![
+                arg("url", Type::String),
+                optional_arg("body", Type::Any),
+                optional_arg("headers", any_object()),
+            ],
+            GenericType::Named(Type::Any),
+            &format!("Performs a remote HTTP {} request.", method),
+            &format!(
+                "RETURN http::{}('https://dummyjson.com/comments/1', {{ body: 'Comment' }});",
+                name
+            ),
+        );
+    }
+
+    map
+}
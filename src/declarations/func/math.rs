@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, array_of, bounded_type_param, generic_arg, insert_function};
+
+pub fn get_math_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, description, example) in [
+        (
+            "abs",
+            "Returns the absolute value of a number.",
+            "RETURN math::abs(-13.746189);\n\n13.746189",
+        ),
+        (
+            "ceil",
+            "Rounds a number up to the next largest integer.",
+            "RETURN math::ceil(13.146572);\n\n14",
+        ),
+        (
+            "floor",
+            "Rounds a number down to the nearest integer.",
+            "RETURN math::floor(13.746189);\n\n13",
+        ),
+        (
+            "round",
+            "Rounds a number to the nearest integer.",
+            "RETURN math::round(13.53124);\n\n14",
+        ),
+        (
+            "sqrt",
+            "Returns the square root of a number.",
+            "RETURN math::sqrt(15);\n\n3.872983346207417",
+        ),
+    ] {
+        // `abs` keeps the kind of number it is given.
+        let ty = if name == "abs" {
+            bounded_type_param(Type::Number)
+        } else {
+            GenericType::Named(Type::Number)
+        };
+        insert_function(
+            &mut map,
+            &format!("math::{}", name),
+            vec![generic_arg("number", ty.clone())],
+            ty,
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "interquartile",
+            "Returns the interquartile range of an array of numbers.",
+            "RETURN math::interquartile([1, 40, 60, 10, 2, 901]);",
+        ),
+        (
+            "max",
+            "Returns the greatest number in an array of numbers.",
+            "RETURN math::max([26.164, 13.746189, 23, 16.4, 41.42]);\n\n41.42",
+        ),
+        (
+            "mean",
+            "Returns the mean of an array of numbers.",
+            "RETURN math::mean([26.164, 13.746189, 23, 16.4, 41.42]);\n\n24.1460378",
+        ),
+        (
+            "median",
+            "Returns the median of an array of numbers.",
+            "RETURN math::median([26.164, 13.746189, 23, 16.4, 41.42]);\n\n23",
+        ),
+        (
+            "midhinge",
+            "Returns the midhinge of an array of numbers.",
+            "RETURN math::midhinge([1, 40, 60, 10, 2, 901]);",
+        ),
+        (
+            "min",
+            "Returns the least number in an array of numbers.",
+            "RETURN math::min([26.164, 13.746189, 23, 16.4, 41.42]);\n\n13.746189",
+        ),
+        (
+            "mode",
+            "Returns the most common number in an array of numbers.",
+            "RETURN math::mode([1, 40, 60, 10, 2, 901]);",
+        ),
+        (
+            "product",
+            "Returns the product of an array of numbers.",
+            "RETURN math::product([26.164, 13.746189, 23, 16.4, 41.42]);",
+        ),
+        (
+            "spread",
+            "Returns the difference between the greatest and least numbers in an array.",
+            "RETURN math::spread([1, 40, 60, 10, 2, 901]);\n\n900",
+        ),
+        (
+            "stddev",
+            "Returns the standard deviation of an array of numbers.",
+            "RETURN math::stddev([1, 40, 60, 10, 2, 901]);",
+        ),
+        (
+            "sum",
+            "Returns the sum of an array of numbers.",
+            "RETURN math::sum([26.164, 13.746189, 23, 16.4, 41.42]);\n\n120.730189",
+        ),
+        (
+            "trimean",
+            "Returns the trimean of an array of numbers.",
+            "RETURN math::trimean([1, 40, 60, 10, 2, 901]);",
+        ),
+        (
+            "variance",
+            "Returns the variance of an array of numbers.",
+            "RETURN math::variance([1, 40, 60, 10, 2, 901]);",
+        ),
+    ] {
+        // `max` and `min` return one of the numbers they are given, while
+        // averages and spreads are fractional.
+        let return_type = match name {
+            "max" | "min" => bounded_type_param(Type::Number),
+            "interquartile" | "mean" | "median" | "midhinge" | "stddev" | "trimean"
+            | "variance" => GenericType::Named(Type::Float),
+            _ => GenericType::Named(Type::Number),
+        };
+        insert_function(
+            &mut map,
+            &format!("math::{}", name),
+            vec![generic_arg(
+                "numbers",
+                GenericType::GenericArray(Box::new(bounded_type_param(Type::Number))),
+            )],
+            return_type,
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "bottom",
+            "Returns the given number of least values in an array of numbers.",
+            "RETURN math::bottom([1, 2, 3], 2);",
+        ),
+        (
+            "top",
+            "Returns the given number of greatest values in an array of numbers.",
+            "RETURN math::top([1, 2, 3], 2);",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("math::{}", name),
+            vec![
+                generic_arg(
+                    "numbers",
+                    GenericType::GenericArray(Box::new(bounded_type_param(Type::Number))),
+                ),
+                arg("count", Type::Int),
+            ],
+            GenericType::GenericArray(Box::new(bounded_type_param(Type::Number))),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "math::fixed",
+        vec![arg("number", Type::Number), arg("precision", Type::Int)],
+        GenericType::Named(Type::Number),
+        "Rounds a number to a fixed number of decimal places.",
+        "RETURN math::fixed(13.146572, 2);\n\n13.15",
+    );
+    for (name, description, example) in [
+        (
+            "nearestrank",
+            "Returns the nearest rank percentile of an array of numbers.",
+            "RETURN math::nearestrank([1, 40, 60, 10, 2, 901], 50);",
+        ),
+        (
+            "percentile",
+            "Returns the value below which a percentage of an array of numbers falls.",
+            "RETURN math::percentile([1, 40, 60, 10, 2, 901], 50);",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("math::{}", name),
+            vec![
+                arg("numbers", array_of(Type::Number)),
+                arg("percentile", Type::Number),
+            ],
+            GenericType::Named(Type::Number),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "math::pow",
+        vec![arg("number", Type::Number), arg("exponent", Type::Number)],
+        GenericType::Named(Type::Number),
+        "Raises a number to a power.",
+        "RETURN math::pow(1.07, 10);",
+    );
+
+    map
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_meta_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "meta::id",
+        vec![arg("record", Type::Any)],
+        GenericType::Named(Type::Any),
+        "Returns the id part of a record id.",
+        "RETURN meta::id(person:tobie);\n\n'tobie'",
+    );
+    for name in ["tb", "table"] {
+        insert_function(
+            &mut map,
+            &format!("meta::{}", name),
+            vec![arg("record", Type::Any)],
+            GenericType::Named(Type::String),
+            "Returns the table name of a record id.",
+            &format!("RETURN meta::{}(person:tobie);\n\n'person'", name),
+        );
+    }
+
+    map
+}
@@ -1 +1,124 @@
+use std::collections::HashMap;
+
+use super::{
+    functions::{ArgKind, Function, FunctionArg, GenericType, Signature},
+    object::Object,
+    type_::Type,
+};
+
+pub mod array;
 pub mod count;
+pub mod crypto;
+pub mod duration;
+pub mod geo;
+pub mod http;
+pub mod math;
+pub mod meta;
+pub mod object;
+pub mod parse;
+pub mod rand;
+pub mod record;
+pub mod search;
+pub mod session;
+pub mod sleep;
+pub mod string;
+pub mod time;
+pub mod type_;
+pub mod vector;
+
+/// Registers the built-in function `name`, documented with a short
+/// description and an example of its use.
+pub fn insert_function(
+    map: &mut HashMap<String, Function>,
+    name: &str,
+    args: Vec<FunctionArg>,
+    return_type: GenericType,
+    description: &str,
+    example: &str,
+) {
+    insert_overloaded_function(
+        map,
+        name,
+        vec![signature(args, return_type)],
+        description,
+        example,
+    );
+}
+
+/// Registers a built-in function which can be called with any of `overloads`.
+pub fn insert_overloaded_function(
+    map: &mut HashMap<String, Function>,
+    name: &str,
+    overloads: Vec<Signature>,
+    description: &str,
+    example: &str,
+) {
+    map.insert(
+        name.to_string(),
+        Function {
+            overloads,
+            doc: Some(format!(
+                "## `{}`\n{}\n\n### Example\n```surql\n{}\n```",
+                name, description, example
+            )),
+        },
+    );
+}
+
+pub fn signature(args: Vec<FunctionArg>, return_type: GenericType) -> Signature {
+    Signature { args, return_type }
+}
+
+pub fn arg(name: &str, ty: Type) -> FunctionArg {
+    FunctionArg(name.to_string(), GenericType::Named(ty), ArgKind::Required)
+}
+
+pub fn generic_arg(name: &str, ty: GenericType) -> FunctionArg {
+    FunctionArg(name.to_string(), ty, ArgKind::Required)
+}
+
+pub fn optional_arg(name: &str, ty: Type) -> FunctionArg {
+    FunctionArg(name.to_string(), GenericType::Named(ty), ArgKind::Optional)
+}
+
+/// An argument which can be repeated any number of times at the end of a call.
+pub fn rest_arg(name: &str, ty: GenericType) -> FunctionArg {
+    FunctionArg(name.to_string(), ty, ArgKind::Rest)
+}
+
+pub fn array_of(ty: Type) -> Type {
+    Type::Array(Box::new(ty))
+}
+
+pub fn option_of(ty: Type) -> Type {
+    Type::Option(Box::new(ty))
+}
+
+/// An object of any shape.
+pub fn any_object() -> Type {
+    Type::Object(Object {
+        fields: vec![],
+        location: None,
+        is_flexible: true,
+    })
+}
+
+/// `T`, inferred from the arguments.
+pub fn type_param() -> GenericType {
+    bounded_type_param(Type::Any)
+}
+
+/// `T`, inferred from the arguments, which must be assignable to `super_`.
+pub fn bounded_type_param(super_: Type) -> GenericType {
+    GenericType::TypeParam { super_ }
+}
+
+/// `option<T>`, where `T` is inferred from the arguments.
+pub fn generic_option() -> GenericType {
+    GenericType::GenericOption(Box::new(type_param()))
+}
+
+/// `array<T>`, where `T` is inferred from the arguments.
+pub fn generic_array() -> GenericType {
+    GenericType::GenericArray(Box::new(type_param()))
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{any_object, arg, array_of, insert_function};
+
+pub fn get_object_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "object::entries",
+        vec![arg("object", any_object())],
+        GenericType::Named(array_of(array_of(Type::Any))),
+        "Returns the `[key, value]` pairs of an object.",
+        "RETURN object::entries({ a: 1, b: true });\n\n[['a', 1], ['b', true]]",
+    );
+    insert_function(
+        &mut map,
+        "object::from_entries",
+        vec![arg("entries", array_of(array_of(Type::Any)))],
+        GenericType::Named(any_object()),
+        "Builds an object from `[key, value]` pairs.",
+        "RETURN object::from_entries([['a', 1], ['b', true]]);\n\n{ a: 1, b: true }",
+    );
+    insert_function(
+        &mut map,
+        "object::keys",
+        vec![arg("object", any_object())],
+        GenericType::Named(array_of(Type::String)),
+        "Returns the keys of an object.",
+        "RETURN object::keys({ a: 1, b: true });\n\n['a', 'b']",
+    );
+    insert_function(
+        &mut map,
+        "object::len",
+        vec![arg("object", any_object())],
+        GenericType::Named(Type::Int),
+        "Returns the number of keys in an object.",
+        "RETURN object::len({ a: 1, b: true });\n\n2",
+    );
+    insert_function(
+        &mut map,
+        "object::values",
+        vec![arg("object", any_object())],
+        GenericType::Named(array_of(Type::Any)),
+        "Returns the values of an object.",
+        "RETURN object::values({ a: 1, b: true });\n\n[1, true]",
+    );
+
+    map
+}
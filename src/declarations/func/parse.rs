@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_parse_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, part, example) in [
+        (
+            "email::host",
+            "host",
+            "RETURN parse::email::host('info@surrealdb.com');\n\n'surrealdb.com'",
+        ),
+        (
+            "email::user",
+            "user",
+            "RETURN parse::email::user('info@surrealdb.com');\n\n'info'",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("parse::{}", name),
+            vec![arg("email", Type::String)],
+            GenericType::Named(Type::String),
+            &format!("Parses the {} from an email address.", part),
+            example,
+        );
+    }
+    for (name, part, example) in [
+        (
+            "domain",
+            "domain",
+            "RETURN parse::url::domain('https://surrealdb.com:80/features?some=option#fragment');\n\n'surrealdb.com'",
+        ),
+        (
+            "fragment",
+            "fragment",
+            "RETURN parse::url::fragment('https://surrealdb.com:80/features?some=option#fragment');\n\n'fragment'",
+        ),
+        (
+            "host",
+            "host",
+            "RETURN parse::url::host('https://surrealdb.com:80/features?some=option#fragment');\n\n'surrealdb.com'",
+        ),
+        (
+            "path",
+            "path",
+            "RETURN parse::url::path('https://surrealdb.com:80/features?some=option#fragment');\n\n'/features'",
+        ),
+        (
+            "query",
+            "query",
+            "RETURN parse::url::query('https://surrealdb.com:80/features?some=option#fragment');\n\n'some=option'",
+        ),
+        (
+            "scheme",
+            "scheme",
+            "RETURN parse::url::scheme('https://surrealdb.com:80/features?some=option#fragment');\n\n'https'",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("parse::url::{}", name),
+            vec![arg("url", Type::String)],
+            GenericType::Named(Type::String),
+            &format!("Parses the {} from a URL.", part),
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "parse::url::port",
+        vec![arg("url", Type::String)],
+        GenericType::Named(Type::Int),
+        "Parses the port from a URL.",
+        "RETURN parse::url::port('https://surrealdb.com:80/features?some=option#fragment');\n\n80",
+    );
+
+    map
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function, insert_overloaded_function, rest_arg, signature};
+
+// There is no `uuid` type yet, so uuids are typed as strings.
+pub fn get_rand_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, ty, description, example) in [
+        (
+            "rand",
+            Type::Float,
+            "Generates a random float between 0 and 1.",
+            "RETURN rand();\n\n0.7062321084863658",
+        ),
+        (
+            "rand::bool",
+            Type::Bool,
+            "Generates a random boolean.",
+            "RETURN rand::bool();\n\ntrue",
+        ),
+        (
+            "rand::guid",
+            Type::String,
+            "Generates a random 20 character guid.",
+            "RETURN rand::guid();\n\n'ig3x7a2lh4yrcgyqakfh'",
+        ),
+        (
+            "rand::time",
+            Type::DateTime,
+            "Generates a random datetime.",
+            "RETURN rand::time();\n\n'2021-11-01T08:51:34Z'",
+        ),
+        (
+            "rand::ulid",
+            Type::String,
+            "Generates a random ULID.",
+            "RETURN rand::ulid();\n\n'01GX4MYWV5KVDX3CEX6NAFXW5H'",
+        ),
+        (
+            "rand::uuid",
+            Type::String,
+            "Generates a random UUID.",
+            "RETURN rand::uuid();\n\n'a2b9e42e-2d3d-4d9e-8bc2-e26e85ea7ab8'",
+        ),
+        (
+            "rand::uuid::v4",
+            Type::String,
+            "Generates a random version 4 UUID.",
+            "RETURN rand::uuid::v4();\n\n'4e2bdd6c-8d21-44a9-9d37-a1e9ba1b86c4'",
+        ),
+        (
+            "rand::uuid::v7",
+            Type::String,
+            "Generates a random version 7 UUID.",
+            "RETURN rand::uuid::v7();\n\n'0190f1a4-7e6b-7a4b-8f3c-7d3a1c9e6b2f'",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            name,
+            vec![],
+            GenericType::Named(ty),
+            description,
+            example,
+        );
+    }
+    for (name, ty, description, example) in [
+        (
+            "rand::float",
+            Type::Float,
+            "Generates a random float, between `min` and `max` if they are given.",
+            "RETURN rand::float(10, 15);\n\n11.136783608674181",
+        ),
+        (
+            "rand::int",
+            Type::Int,
+            "Generates a random integer, between `min` and `max` if they are given.",
+            "RETURN rand::int(10, 15);\n\n12",
+        ),
+    ] {
+        insert_overloaded_function(
+            &mut map,
+            name,
+            vec![
+                signature(vec![], GenericType::Named(ty.clone())),
+                signature(
+                    vec![arg("min", Type::Number), arg("max", Type::Number)],
+                    GenericType::Named(ty),
+                ),
+            ],
+            description,
+            example,
+        );
+    }
+    insert_overloaded_function(
+        &mut map,
+        "rand::string",
+        vec![
+            signature(vec![], GenericType::Named(Type::String)),
+            signature(
+                vec![arg("length", Type::Int)],
+                GenericType::Named(Type::String),
+            ),
+            signature(
+                vec![arg("min", Type::Int), arg("max", Type::Int)],
+                GenericType::Named(Type::String),
+            ),
+        ],
+        "Generates a random string, 32 characters long unless a length or a range of lengths is given.",
+        "RETURN rand::string(15);\n\n'XeBgmRRpFGfHZgB'",
+    );
+    insert_function(
+        &mut map,
+        "rand::enum",
+        vec![rest_arg("values", GenericType::Named(Type::Any))],
+        GenericType::Named(Type::Any),
+        "Returns one of its arguments at random.",
+        "RETURN rand::enum('one', 'two', 3, 4.15385, 'five', true);\n\n'five'",
+    );
+
+    map
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_record_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "record::exists",
+        vec![arg("record", Type::Any)],
+        GenericType::Named(Type::Bool),
+        "Checks whether a record exists.",
+        "RETURN record::exists(person:tobie);",
+    );
+    insert_function(
+        &mut map,
+        "record::id",
+        vec![arg("record", Type::Any)],
+        GenericType::Named(Type::Any),
+        "Returns the id part of a record id.",
+        "RETURN record::id(person:tobie);\n\n'tobie'",
+    );
+    for name in ["tb", "table"] {
+        insert_function(
+            &mut map,
+            &format!("record::{}", name),
+            vec![arg("record", Type::Any)],
+            GenericType::Named(Type::String),
+            "Returns the table name of a record id.",
+            &format!("RETURN record::{}(person:tobie);\n\n'person'", name),
+        );
+    }
+
+    map
+}
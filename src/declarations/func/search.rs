@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{any_object, arg, array_of, insert_function, optional_arg};
+
+pub fn get_search_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "search::analyze",
+        vec![arg("analyzer", Type::String), arg("text", Type::String)],
+        GenericType::Named(array_of(Type::String)),
+        "Returns the tokens an analyzer produces for a string.",
+        "RETURN search::analyze('book_analyzer', 'A hands-on guide');",
+    );
+    insert_function(
+        &mut map,
+        "search::highlight",
+        vec![
+            arg("prefix", Type::String),
+            arg("suffix", Type::String),
+            arg("predicate", Type::Int),
+            optional_arg("partial", Type::Bool),
+        ],
+        GenericType::Named(Type::Any),
+        "Highlights the terms matched by a full-text search predicate.",
+        "SELECT search::highlight('<b>', '</b>', 1) AS title\nFROM book WHERE title @1@ 'rust web';",
+    );
+    insert_function(
+        &mut map,
+        "search::offsets",
+        vec![
+            arg("predicate", Type::Int),
+            optional_arg("partial", Type::Bool),
+        ],
+        GenericType::Named(any_object()),
+        "Returns the positions of the terms matched by a full-text search predicate.",
+        "SELECT search::offsets(0) AS title_offsets\nFROM book WHERE title @0@ 'rust web';",
+    );
+    insert_function(
+        &mut map,
+        "search::score",
+        vec![arg("predicate", Type::Int)],
+        GenericType::Named(Type::Number),
+        "Returns the relevance score of a full-text search predicate.",
+        "SELECT search::score(1) AS score\nFROM book WHERE title @1@ 'rust web'\nORDER BY score DESC;",
+    );
+
+    map
+}
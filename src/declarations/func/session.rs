@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{any_object, insert_function};
+
+pub fn get_session_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, description) in [
+        ("db", "Returns the database of the current session."),
+        ("id", "Returns the id of the current session."),
+        ("ip", "Returns the IP address of the current session."),
+        ("ns", "Returns the namespace of the current session."),
+        ("origin", "Returns the HTTP origin of the current session."),
+        ("sc", "Returns the scope of the current session."),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("session::{}", name),
+            vec![],
+            GenericType::Named(Type::String),
+            description,
+            &format!("RETURN session::{}();", name),
+        );
+    }
+    insert_function(
+        &mut map,
+        "session::token",
+        vec![],
+        GenericType::Named(any_object()),
+        "Returns the claims of the token used to authenticate the current session.",
+        "RETURN session::token();",
+    );
+
+    map
+}
@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, insert_function};
+
+pub fn get_sleep_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    insert_function(
+        &mut map,
+        "sleep",
+        vec![arg("duration", Type::Duration)],
+        GenericType::Named(Type::Null),
+        "Delays the current query for a duration.",
+        "RETURN sleep(500ms);\n\nnull",
+    );
+
+    map
+}
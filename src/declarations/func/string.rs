@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, array_of, insert_function, optional_arg, rest_arg};
+
+pub fn get_string_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, description, example) in [
+        (
+            "lowercase",
+            "Converts a string to lowercase.",
+            "RETURN string::lowercase('THIS IS A TEST');\n\n'this is a test'",
+        ),
+        (
+            "reverse",
+            "Reverses a string.",
+            "RETURN string::reverse('this is a test');\n\n'tset a si siht'",
+        ),
+        (
+            "slug",
+            "Converts a string into a human and URL-friendly string.",
+            "RETURN string::slug('SurrealDB has launched #database #awesome');\n\n'surrealdb-has-launched-database-awesome'",
+        ),
+        (
+            "trim",
+            "Removes whitespace from the start and end of a string.",
+            "RETURN string::trim('    this is a test    ');\n\n'this is a test'",
+        ),
+        (
+            "uppercase",
+            "Converts a string to uppercase.",
+            "RETURN string::uppercase('this is a test');\n\n'THIS IS A TEST'",
+        ),
+        (
+            "semver::inc::major",
+            "Increments the major part of a semantic version.",
+            "RETURN string::semver::inc::major('1.2.3');\n\n'2.0.0'",
+        ),
+        (
+            "semver::inc::minor",
+            "Increments the minor part of a semantic version.",
+            "RETURN string::semver::inc::minor('1.2.3');\n\n'1.3.0'",
+        ),
+        (
+            "semver::inc::patch",
+            "Increments the patch part of a semantic version.",
+            "RETURN string::semver::inc::patch('1.2.3');\n\n'1.2.4'",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::{}", name),
+            vec![arg("string", Type::String)],
+            GenericType::Named(Type::String),
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "len",
+            "Returns the length of a string.",
+            "RETURN string::len('this is a test');\n\n14",
+        ),
+        (
+            "semver::major",
+            "Returns the major part of a semantic version.",
+            "RETURN string::semver::major('1.2.3');\n\n1",
+        ),
+        (
+            "semver::minor",
+            "Returns the minor part of a semantic version.",
+            "RETURN string::semver::minor('1.2.3');\n\n2",
+        ),
+        (
+            "semver::patch",
+            "Returns the patch part of a semantic version.",
+            "RETURN string::semver::patch('1.2.3');\n\n3",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::{}", name),
+            vec![arg("string", Type::String)],
+            GenericType::Named(Type::Int),
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "alphanum",
+            "Checks whether a string contains only letters and numbers.",
+            "RETURN string::is::alphanum('ABC123');\n\ntrue",
+        ),
+        (
+            "alpha",
+            "Checks whether a string contains only letters.",
+            "RETURN string::is::alpha('ABCDEF');\n\ntrue",
+        ),
+        (
+            "ascii",
+            "Checks whether a string contains only ASCII characters.",
+            "RETURN string::is::ascii('ABC123');\n\ntrue",
+        ),
+        (
+            "domain",
+            "Checks whether a string is a domain name.",
+            "RETURN string::is::domain('surrealdb.com');\n\ntrue",
+        ),
+        (
+            "email",
+            "Checks whether a string is an email address.",
+            "RETURN string::is::email('info@surrealdb.com');\n\ntrue",
+        ),
+        (
+            "hexadecimal",
+            "Checks whether a string is hexadecimal.",
+            "RETURN string::is::hexadecimal('ff009e');\n\ntrue",
+        ),
+        (
+            "latitude",
+            "Checks whether a string is a latitude value.",
+            "RETURN string::is::latitude('-0.118092');\n\ntrue",
+        ),
+        (
+            "longitude",
+            "Checks whether a string is a longitude value.",
+            "RETURN string::is::longitude('51.509865');\n\ntrue",
+        ),
+        (
+            "numeric",
+            "Checks whether a string contains only numbers.",
+            "RETURN string::is::numeric('1484091748');\n\ntrue",
+        ),
+        (
+            "semver",
+            "Checks whether a string is a semantic version.",
+            "RETURN string::is::semver('1.0.0');\n\ntrue",
+        ),
+        (
+            "url",
+            "Checks whether a string is a URL.",
+            "RETURN string::is::url('https://surrealdb.com');\n\ntrue",
+        ),
+        (
+            "uuid",
+            "Checks whether a string is a UUID.",
+            "RETURN string::is::uuid('e72bee20-f49b-11ec-b939-0242ac120002');\n\ntrue",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::is::{}", name),
+            vec![arg("string", Type::String)],
+            GenericType::Named(Type::Bool),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "string::is::datetime",
+        vec![arg("string", Type::String), arg("format", Type::String)],
+        GenericType::Named(Type::Bool),
+        "Checks whether a string is a datetime in the given format.",
+        "RETURN string::is::datetime('2015-09-05 23:56:04', '%Y-%m-%d %H:%M:%S');\n\ntrue",
+    );
+    for (name, description, example) in [
+        (
+            "contains",
+            "Checks whether a string contains another string.",
+            "RETURN string::contains('abcdefg', 'cde');\n\ntrue",
+        ),
+        (
+            "ends_with",
+            "Checks whether a string ends with another string.",
+            "RETURN string::ends_with('some test', 'test');\n\ntrue",
+        ),
+        (
+            "starts_with",
+            "Checks whether a string starts with another string.",
+            "RETURN string::starts_with('some test', 'some');\n\ntrue",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::{}", name),
+            vec![arg("string", Type::String), arg("predicate", Type::String)],
+            GenericType::Named(Type::Bool),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "string::concat",
+        vec![rest_arg("values", GenericType::Named(Type::Any))],
+        GenericType::Named(Type::String),
+        "Concatenates strings together.",
+        "RETURN string::concat('this', ' ', 'is', ' ', 'a', ' ', 'test');\n\n'this is a test'",
+    );
+    insert_function(
+        &mut map,
+        "string::join",
+        vec![
+            arg("delimiter", Type::String),
+            rest_arg("values", GenericType::Named(Type::Any)),
+        ],
+        GenericType::Named(Type::String),
+        "Joins strings together with a delimiter.",
+        "RETURN string::join(', ', 'a', 'list', 'of', 'items');\n\n'a, list, of, items'",
+    );
+    insert_function(
+        &mut map,
+        "string::repeat",
+        vec![arg("string", Type::String), arg("count", Type::Int)],
+        GenericType::Named(Type::String),
+        "Repeats a string a number of times.",
+        "RETURN string::repeat('test', 3);\n\n'testtesttest'",
+    );
+    insert_function(
+        &mut map,
+        "string::replace",
+        vec![
+            arg("string", Type::String),
+            arg("search", Type::String),
+            arg("replacement", Type::String),
+        ],
+        GenericType::Named(Type::String),
+        "Replaces every occurrence of one string in another.",
+        "RETURN string::replace('this is a test', 'a test', 'awesome');\n\n'this is awesome'",
+    );
+    insert_function(
+        &mut map,
+        "string::slice",
+        vec![
+            arg("string", Type::String),
+            optional_arg("start", Type::Int),
+            optional_arg("length", Type::Int),
+        ],
+        GenericType::Named(Type::String),
+        "Extracts part of a string, from a start index and of a given length.",
+        "RETURN string::slice('this is a test', 10, 4);\n\n'test'",
+    );
+    insert_function(
+        &mut map,
+        "string::split",
+        vec![arg("string", Type::String), arg("delimiter", Type::String)],
+        GenericType::Named(array_of(Type::String)),
+        "Splits a string by a delimiter.",
+        "RETURN string::split('this, is, a, list', ', ');\n\n['this', 'is', 'a', 'list']",
+    );
+    insert_function(
+        &mut map,
+        "string::words",
+        vec![arg("string", Type::String)],
+        GenericType::Named(array_of(Type::String)),
+        "Splits a string into its words.",
+        "RETURN string::words('this is a test');\n\n['this', 'is', 'a', 'test']",
+    );
+    for (name, description, example) in [
+        (
+            "distance::hamming",
+            "Returns the Hamming distance between two strings of equal length.",
+            "RETURN string::distance::hamming('hello', 'hallo');\n\n1",
+        ),
+        (
+            "distance::levenshtein",
+            "Returns the Levenshtein distance between two strings.",
+            "RETURN string::distance::levenshtein('kitten', 'sitting');\n\n3",
+        ),
+        (
+            "similarity::fuzzy",
+            "Returns a fuzzy similarity score for two strings.",
+            "RETURN string::similarity::fuzzy('DB', 'SurrealDB');",
+        ),
+        (
+            "similarity::smithwaterman",
+            "Returns the Smith-Waterman similarity score for two strings.",
+            "RETURN string::similarity::smithwaterman('DB', 'SurrealDB');",
+        ),
+        (
+            "semver::compare",
+            "Compares two semantic versions, returning -1, 0 or 1.",
+            "RETURN string::semver::compare('1.0.0', '1.3.5');\n\n-1",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::{}", name),
+            vec![rest_arg("values", GenericType::Named(Type::Any))],
+            GenericType::Named(Type::Int),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "string::similarity::jaro",
+        vec![rest_arg("values", GenericType::Named(Type::Any))],
+        GenericType::Named(Type::Float),
+        "Returns the Jaro similarity of two strings.",
+        "RETURN string::similarity::jaro('DB', 'SurrealDB');",
+    );
+    for (name, example) in [
+        (
+            "major",
+            "RETURN string::semver::set::major('1.2.3', 9);\n\n'9.2.3'",
+        ),
+        (
+            "minor",
+            "RETURN string::semver::set::minor('1.2.3', 9);\n\n'1.9.3'",
+        ),
+        (
+            "patch",
+            "RETURN string::semver::set::patch('1.2.3', 9);\n\n'1.2.9'",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("string::semver::set::{}", name),
+            vec![arg("version", Type::String), arg("value", Type::Int)],
+            GenericType::Named(Type::String),
+            &format!("Sets the {} part of a semantic version.", name),
+            example,
+        );
+    }
+
+    map
+}
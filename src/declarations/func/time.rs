@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, array_of, insert_function};
+
+pub fn get_time_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, description, example) in [
+        (
+            "day",
+            "Extracts the day of the month from a datetime.",
+            "RETURN time::day(d\"2021-11-01T08:30:17+00:00\");\n\n1",
+        ),
+        (
+            "hour",
+            "Extracts the hour from a datetime.",
+            "RETURN time::hour(d\"2021-11-01T08:30:17+00:00\");\n\n8",
+        ),
+        (
+            "micros",
+            "Returns the number of microseconds since the UNIX epoch.",
+            "RETURN time::micros(d\"1987-06-22T08:30:45Z\");\n\n551349045000000",
+        ),
+        (
+            "millis",
+            "Returns the number of milliseconds since the UNIX epoch.",
+            "RETURN time::millis(d\"1987-06-22T08:30:45Z\");\n\n551349045000",
+        ),
+        (
+            "minute",
+            "Extracts the minutes from a datetime.",
+            "RETURN time::minute(d\"2021-11-01T08:30:17+00:00\");\n\n30",
+        ),
+        (
+            "month",
+            "Extracts the month from a datetime.",
+            "RETURN time::month(d\"2021-11-01T08:30:17+00:00\");\n\n11",
+        ),
+        (
+            "nano",
+            "Returns the number of nanoseconds since the UNIX epoch.",
+            "RETURN time::nano(d\"1987-06-22T08:30:45Z\");\n\n551349045000000000",
+        ),
+        (
+            "second",
+            "Extracts the seconds from a datetime.",
+            "RETURN time::second(d\"2021-11-01T08:30:17+00:00\");\n\n17",
+        ),
+        (
+            "unix",
+            "Returns the number of seconds since the UNIX epoch.",
+            "RETURN time::unix(d\"2021-11-01T08:30:17+00:00\");\n\n1635755417",
+        ),
+        (
+            "wday",
+            "Extracts the day of the week from a datetime, starting from Monday.",
+            "RETURN time::wday(d\"2021-11-01T08:30:17+00:00\");\n\n1",
+        ),
+        (
+            "week",
+            "Extracts the week of the year from a datetime.",
+            "RETURN time::week(d\"2021-11-01T08:30:17+00:00\");\n\n44",
+        ),
+        (
+            "yday",
+            "Extracts the day of the year from a datetime.",
+            "RETURN time::yday(d\"2021-11-01T08:30:17+00:00\");\n\n305",
+        ),
+        (
+            "year",
+            "Extracts the year from a datetime.",
+            "RETURN time::year(d\"2021-11-01T08:30:17+00:00\");\n\n2021",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("time::{}", name),
+            vec![arg("datetime", Type::DateTime)],
+            GenericType::Named(Type::Int),
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "ceil",
+            "Rounds a datetime up to the next largest multiple of a duration.",
+            "RETURN time::ceil(d\"2021-11-01T08:30:17+00:00\", 1w);\n\nd\"2021-11-04T00:00:00Z\"",
+        ),
+        (
+            "floor",
+            "Rounds a datetime down to the nearest multiple of a duration.",
+            "RETURN time::floor(d\"2021-11-01T08:30:17+00:00\", 1w);\n\nd\"2021-10-28T00:00:00Z\"",
+        ),
+        (
+            "round",
+            "Rounds a datetime to the nearest multiple of a duration.",
+            "RETURN time::round(d\"2021-11-01T08:30:17+00:00\", 1w);\n\nd\"2021-11-04T00:00:00Z\"",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("time::{}", name),
+            vec![
+                arg("datetime", Type::DateTime),
+                arg("duration", Type::Duration),
+            ],
+            GenericType::Named(Type::DateTime),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "time::format",
+        vec![arg("datetime", Type::DateTime), arg("format", Type::String)],
+        GenericType::Named(Type::String),
+        "Formats a datetime as a string.",
+        "RETURN time::format(d\"2021-11-01T08:30:17+00:00\", \"%Y-%m-%d\");\n\n\"2021-11-01\"",
+    );
+    insert_function(
+        &mut map,
+        "time::group",
+        vec![arg("datetime", Type::DateTime), arg("unit", Type::String)],
+        GenericType::Named(Type::DateTime),
+        "Truncates a datetime to the start of a unit, such as `year`, `month` or `hour`.",
+        "RETURN time::group(d\"2021-11-01T08:30:17+00:00\", \"year\");\n\nd\"2021-01-01T00:00:00Z\"",
+    );
+    for (name, description, example) in [
+        (
+            "max",
+            "Returns the latest datetime in an array of datetimes.",
+            "RETURN time::max([d\"1987-06-22T08:30:45Z\", d\"1988-06-22T08:30:45Z\"]);\n\nd\"1988-06-22T08:30:45Z\"",
+        ),
+        (
+            "min",
+            "Returns the earliest datetime in an array of datetimes.",
+            "RETURN time::min([d\"1987-06-22T08:30:45Z\", d\"1988-06-22T08:30:45Z\"]);\n\nd\"1987-06-22T08:30:45Z\"",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("time::{}", name),
+            vec![arg("datetimes", array_of(Type::DateTime))],
+            GenericType::Named(Type::DateTime),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "time::now",
+        vec![],
+        GenericType::Named(Type::DateTime),
+        "Returns the current datetime.",
+        "RETURN time::now();",
+    );
+    insert_function(
+        &mut map,
+        "time::timezone",
+        vec![],
+        GenericType::Named(Type::String),
+        "Returns the local timezone offset of the server.",
+        "RETURN time::timezone();\n\n\"+05:30\"",
+    );
+    for (name, unit, example) in [
+        (
+            "micros",
+            "microseconds",
+            "RETURN time::from::micros(1000000);\n\nd\"1970-01-01T00:00:01Z\"",
+        ),
+        (
+            "millis",
+            "milliseconds",
+            "RETURN time::from::millis(1000);\n\nd\"1970-01-01T00:00:01Z\"",
+        ),
+        (
+            "nanos",
+            "nanoseconds",
+            "RETURN time::from::nanos(1000000000);\n\nd\"1970-01-01T00:00:01Z\"",
+        ),
+        (
+            "secs",
+            "seconds",
+            "RETURN time::from::secs(1);\n\nd\"1970-01-01T00:00:01Z\"",
+        ),
+        (
+            "unix",
+            "seconds",
+            "RETURN time::from::unix(1);\n\nd\"1970-01-01T00:00:01Z\"",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("time::from::{}", name),
+            vec![arg(unit, Type::Int)],
+            GenericType::Named(Type::DateTime),
+            &format!(
+                "Converts a number of {} since the UNIX epoch into a datetime.",
+                unit
+            ),
+            example,
+        );
+    }
+
+    map
+}
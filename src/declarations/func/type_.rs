@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, array_of, insert_function, optional_arg};
+
+pub fn get_type_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, ty, example) in [
+        ("bool", Type::Bool, "RETURN type::bool(\"true\");\n\ntrue"),
+        (
+            "datetime",
+            Type::DateTime,
+            "RETURN type::datetime(\"2022-04-27T18:12:27+00:00\");\n\nd\"2022-04-27T18:12:27Z\"",
+        ),
+        (
+            "decimal",
+            Type::Decimal,
+            "RETURN type::decimal(\"12345\");\n\n12345dec",
+        ),
+        (
+            "duration",
+            Type::Duration,
+            "RETURN type::duration(\"4h\");\n\n4h",
+        ),
+        (
+            "float",
+            Type::Float,
+            "RETURN type::float(\"12345\");\n\n12345f",
+        ),
+        ("int", Type::Int, "RETURN type::int(\"12345\");\n\n12345"),
+        (
+            "number",
+            Type::Number,
+            "RETURN type::number(\"12345\");\n\n12345",
+        ),
+        (
+            "string",
+            Type::String,
+            "RETURN type::string(12345);\n\n\"12345\"",
+        ),
+        (
+            "point",
+            Type::Any,
+            "RETURN type::point([51.509865, -0.118092]);\n\n(51.509865, -0.118092)",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("type::{}", name),
+            vec![arg("value", Type::Any)],
+            GenericType::Named(ty.clone()),
+            &format!("Converts a value into a {}.", name),
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "type::table",
+        vec![arg("name", Type::Any)],
+        GenericType::Named(Type::String),
+        "Converts a value into a table name.",
+        "SELECT * FROM type::table($table);",
+    );
+    insert_function(
+        &mut map,
+        "type::thing",
+        vec![arg("table", Type::Any), optional_arg("id", Type::Any)],
+        GenericType::Named(Type::Any),
+        "Builds a record id from a table name and an id, or converts a value into a record id.",
+        "RETURN type::thing(\"person\", \"tobie\");\n\nperson:tobie",
+    );
+    insert_function(
+        &mut map,
+        "type::field",
+        vec![arg("path", Type::String)],
+        GenericType::Named(Type::Any),
+        "Projects the field at a path, given as a string.",
+        "SELECT type::field($field) FROM person;",
+    );
+    insert_function(
+        &mut map,
+        "type::fields",
+        vec![arg("paths", array_of(Type::String))],
+        GenericType::Named(array_of(Type::Any)),
+        "Projects the fields at multiple paths, given as strings.",
+        "SELECT type::fields([$first, $second]) FROM person;",
+    );
+    for (name, kind) in [
+        ("array", "an array"),
+        ("bool", "a boolean"),
+        ("bytes", "bytes"),
+        ("collection", "a geometry collection"),
+        ("datetime", "a datetime"),
+        ("decimal", "a decimal"),
+        ("duration", "a duration"),
+        ("float", "a float"),
+        ("geometry", "a geometry"),
+        ("int", "an integer"),
+        ("line", "a geometry line"),
+        ("none", "NONE"),
+        ("null", "null"),
+        ("multiline", "a geometry multiline"),
+        ("multipoint", "a geometry multipoint"),
+        ("multipolygon", "a geometry multipolygon"),
+        ("number", "a number"),
+        ("object", "an object"),
+        ("point", "a geometry point"),
+        ("polygon", "a geometry polygon"),
+        ("record", "a record id"),
+        ("string", "a string"),
+        ("uuid", "a UUID"),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("type::is::{}", name),
+            vec![arg("value", Type::Any)],
+            GenericType::Named(Type::Bool),
+            &format!("Checks whether a value is {}.", kind),
+            &format!("RETURN type::is::{}($value);", name),
+        );
+    }
+
+    map
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::declarations::{
+    functions::{Function, GenericType},
+    type_::Type,
+};
+
+use super::{arg, array_of, insert_function};
+
+pub fn get_vector_functions() -> HashMap<String, Function> {
+    let mut map: HashMap<String, Function> = HashMap::new();
+
+    for (name, description, example) in [
+        (
+            "add",
+            "Adds two vectors element-wise.",
+            "RETURN vector::add([1, 2, 3], [1, 2, 3]);\n\n[2, 4, 6]",
+        ),
+        (
+            "cross",
+            "Returns the cross product of two 3-dimensional vectors.",
+            "RETURN vector::cross([1, 2, 3], [4, 5, 6]);\n\n[-3, 6, -3]",
+        ),
+        (
+            "divide",
+            "Divides two vectors element-wise.",
+            "RETURN vector::divide([10, -20, 30, 0], [0, -1, 2, -3]);\n\n[NaN, 20, 15, 0]",
+        ),
+        (
+            "multiply",
+            "Multiplies two vectors element-wise.",
+            "RETURN vector::multiply([1, 2, 3], [1, 2, 3]);\n\n[1, 4, 9]",
+        ),
+        (
+            "project",
+            "Projects one vector onto another.",
+            "RETURN vector::project([1, -2, 3], [4, 0, 5]);",
+        ),
+        (
+            "subtract",
+            "Subtracts two vectors element-wise.",
+            "RETURN vector::subtract([4, 5, 6], [3, 2, 1]);\n\n[1, 3, 5]",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("vector::{}", name),
+            vec![
+                arg("lhs", array_of(Type::Number)),
+                arg("rhs", array_of(Type::Number)),
+            ],
+            GenericType::Named(array_of(Type::Number)),
+            description,
+            example,
+        );
+    }
+    for (name, description, example) in [
+        (
+            "angle",
+            "Returns the angle between two vectors, in radians.",
+            "RETURN vector::angle([5, 10, 15], [10, 5, 20]);",
+        ),
+        (
+            "dot",
+            "Returns the dot product of two vectors.",
+            "RETURN vector::dot([1, 2, 3], [1, 2, 3]);\n\n14",
+        ),
+        (
+            "distance::chebyshev",
+            "Returns the Chebyshev distance between two vectors.",
+            "RETURN vector::distance::chebyshev([2, 4, 5, 3, 8, 2], [3, 1, 5, -3, 7, 2]);\n\n6",
+        ),
+        (
+            "distance::euclidean",
+            "Returns the Euclidean distance between two vectors.",
+            "RETURN vector::distance::euclidean([10, 50, 200], [400, 100, 20]);",
+        ),
+        (
+            "distance::hamming",
+            "Returns the number of positions at which two vectors differ.",
+            "RETURN vector::distance::hamming([1, 2, 2], [1, 2, 3]);\n\n1",
+        ),
+        (
+            "distance::manhattan",
+            "Returns the Manhattan distance between two vectors.",
+            "RETURN vector::distance::manhattan([10, 20, 15, 10, 5], [12, 24, 18, 8, 7]);\n\n13",
+        ),
+        (
+            "similarity::cosine",
+            "Returns the cosine similarity of two vectors.",
+            "RETURN vector::similarity::cosine([10, 50, 200], [400, 100, 20]);",
+        ),
+        (
+            "similarity::jaccard",
+            "Returns the Jaccard similarity of two vectors.",
+            "RETURN vector::similarity::jaccard([0, 1, 2, 5, 6], [0, 2, 3, 4, 5, 7, 9]);",
+        ),
+        (
+            "similarity::pearson",
+            "Returns the Pearson correlation coefficient of two vectors.",
+            "RETURN vector::similarity::pearson([1, 2, 3], [1, 5, 7]);",
+        ),
+        (
+            "similarity::spearman",
+            "Returns the Spearman correlation coefficient of two vectors.",
+            "RETURN vector::similarity::spearman([1, 2, 3], [1, 5, 7]);",
+        ),
+    ] {
+        insert_function(
+            &mut map,
+            &format!("vector::{}", name),
+            vec![
+                arg("lhs", array_of(Type::Number)),
+                arg("rhs", array_of(Type::Number)),
+            ],
+            GenericType::Named(Type::Number),
+            description,
+            example,
+        );
+    }
+    insert_function(
+        &mut map,
+        "vector::distance::minkowski",
+        vec![
+            arg("lhs", array_of(Type::Number)),
+            arg("rhs", array_of(Type::Number)),
+            arg("order", Type::Number),
+        ],
+        GenericType::Named(Type::Number),
+        "Returns the Minkowski distance between two vectors.",
+        "RETURN vector::distance::minkowski([10, 20, 15, 10, 5], [12, 24, 18, 8, 7], 3);",
+    );
+    insert_function(
+        &mut map,
+        "vector::distance::knn",
+        vec![],
+        GenericType::Named(Type::Number),
+        "Returns the distance computed by the KNN operator of the current query.",
+        "SELECT id, vector::distance::knn() AS distance FROM pts\nWHERE point <|2|> [2, 3, 4, 5];",
+    );
+    for (name, description, example) in [
+        (
+            "magnitude",
+            "Returns the magnitude of a vector.",
+            "RETURN vector::magnitude([1, 2, 3, 3, 3, 4, 5]);",
+        ),
+        (
+            "normalize",
+            "Scales a vector to a length of 1.",
+            "RETURN vector::normalize([4, 3]);\n\n[0.8, 0.6]",
+        ),
+    ] {
+        let return_type = if name == "magnitude" {
+            Type::Number
+        } else {
+            array_of(Type::Number)
+        };
+        insert_function(
+            &mut map,
+            &format!("vector::{}", name),
+            vec![arg("vector", array_of(Type::Number))],
+            GenericType::Named(return_type),
+            description,
+            example,
+        );
+    }
+
+    map
+}
@@ -1,13 +1,31 @@
 use std::{collections::HashMap, fmt::Display};
 
-use super::{func::count::get_count_functions, type_::Type};
+use super::{
+    func::{
+        array::get_array_functions, count::get_count_functions, crypto::get_crypto_functions,
+        duration::get_duration_functions, geo::get_geo_functions, http::get_http_functions,
+        math::get_math_functions, meta::get_meta_functions, object::get_object_functions,
+        parse::get_parse_functions, rand::get_rand_functions, record::get_record_functions,
+        search::get_search_functions, session::get_session_functions, sleep::get_sleep_functions,
+        string::get_string_functions, time::get_time_functions, type_::get_type_functions,
+        vector::get_vector_functions,
+    },
+    type_::Type,
+};
 
+/// The type of a function argument or return value, which can mention the
+/// signature's type parameter. A signature has at most one type parameter,
+/// `T`: every `TypeParam` in it stands for the same type, which `unify`
+/// infers from all the arguments together.
 #[derive(Clone, Debug)]
 pub enum GenericType {
     Named(Type),
-    TypeParam { super_: Type },
-    GenericArray { inner_super_: Type },
-    GenericOption { inner_super_: Type },
+    /// The signature's type parameter `T`, which must be assignable to `super_`.
+    TypeParam {
+        super_: Type,
+    },
+    GenericArray(Box<GenericType>),
+    GenericOption(Box<GenericType>),
 }
 
 impl Display for GenericType {
@@ -15,92 +33,246 @@ impl Display for GenericType {
         match self {
             GenericType::Named(ref t) => write!(f, "{}", t),
             GenericType::TypeParam { super_: _ } => write!(f, "T"),
-            GenericType::GenericArray { inner_super_: _ } => write!(f, "array<T>"),
-            GenericType::GenericOption { inner_super_: _ } => write!(f, "option<T>"),
+            GenericType::GenericArray(ref inner) => write!(f, "array<{}>", inner),
+            GenericType::GenericOption(ref inner) => write!(f, "option<{}>", inner),
         }
     }
 }
 
+impl GenericType {
+    /// The most general type an argument of this type can be given.
+    pub fn bound(&self) -> Type {
+        match self {
+            GenericType::Named(ref t) => t.clone(),
+            GenericType::TypeParam { super_ } => super_.clone(),
+            GenericType::GenericArray(inner) => Type::Array(Box::new(inner.bound())),
+            GenericType::GenericOption(inner) => Type::Option(Box::new(inner.bound())),
+        }
+    }
+
+    /// The bound of the type parameter, if this type mentions it.
+    pub fn get_type_param_bound(&self) -> Option<Type> {
+        match self {
+            GenericType::Named(_) => None,
+            GenericType::TypeParam { super_ } => Some(super_.clone()),
+            GenericType::GenericArray(inner) | GenericType::GenericOption(inner) => {
+                inner.get_type_param_bound()
+            }
+        }
+    }
+
+    /// Matches `actual` against this type, widening `found` to cover whatever
+    /// the type parameter is bound to. Returns false if `actual` cannot be
+    /// passed as this type.
+    pub fn unify(&self, actual: &Type, found: &mut Option<Type>) -> bool {
+        if let Type::Error = actual {
+            return true;
+        }
+        match self {
+            GenericType::Named(ref t) => *actual == Type::Any || t.is_assignable_to(actual),
+            GenericType::TypeParam { super_ } => {
+                if !super_.is_assignable_to(actual) {
+                    return false;
+                }
+                *found = Some(match found {
+                    Some(found_type) => found_type.get_shared_super_type(actual),
+                    None => actual.clone(),
+                });
+                true
+            }
+            GenericType::GenericArray(inner) => match actual {
+                Type::Array(actual_inner) => inner.unify(actual_inner, found),
+                Type::Any => true,
+                _ => false,
+            },
+            GenericType::GenericOption(inner) => match actual {
+                Type::Option(actual_inner) => inner.unify(actual_inner, found),
+                Type::Null => true,
+                _ => inner.unify(actual, found),
+            },
+        }
+    }
+
+    /// Replaces the type parameter with `arg`.
+    pub fn substitute(&self, arg: &Type) -> Type {
+        match self {
+            GenericType::Named(ref t) => t.clone(),
+            GenericType::TypeParam { super_: _ } => arg.clone(),
+            GenericType::GenericArray(inner) => Type::Array(Box::new(inner.substitute(arg))),
+            GenericType::GenericOption(inner) => Type::Option(Box::new(inner.substitute(arg))),
+        }
+    }
+}
+
+/// Functions which, in a `SELECT` with a `GROUP` clause, are called once for
+/// each group with the values of every record in it.
+pub const AGGREGATE_FUNCTIONS: [&str; 14] = [
+    "count",
+    "array::distinct",
+    "array::first",
+    "array::flatten",
+    "array::group",
+    "array::last",
+    "math::max",
+    "math::mean",
+    "math::min",
+    "math::stddev",
+    "math::sum",
+    "math::variance",
+    "time::max",
+    "time::min",
+];
+
+/// A built-in function, which may have several overloaded signatures.
 #[derive(Clone, Debug)]
 pub struct Function {
+    pub overloads: Vec<Signature>,
+    pub doc: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Signature {
     pub args: Vec<FunctionArg>,
     pub return_type: GenericType,
-    pub doc: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArgKind {
+    Required,
+    Optional,
+    /// Accepts any number of trailing arguments, so must come last.
+    Rest,
 }
 
 #[derive(Clone, Debug)]
-pub struct FunctionArg(pub String, pub GenericType);
+pub struct FunctionArg(pub String, pub GenericType, pub ArgKind);
 
 impl Function {
-    pub fn get_type(&self, arg: Type) -> Type {
-        match self.return_type {
-            GenericType::Named(ref t) => t.clone(),
-            GenericType::TypeParam { super_: _ } => arg,
-            GenericType::GenericArray { inner_super_: _ } => Type::Array(Box::new(arg)),
-            GenericType::GenericOption { inner_super_: _ } => Type::Option(Box::new(arg)),
+    /// The first overload the arguments can be passed to, falling back to one
+    /// taking the same number of arguments.
+    pub fn get_overload(&self, args: &[Type]) -> Option<&Signature> {
+        self.overloads
+            .iter()
+            .find(|signature| signature.matches(args))
+            .or_else(|| {
+                self.overloads
+                    .iter()
+                    .find(|signature| signature.accepts_count(args.len()))
+            })
+    }
+
+    /// The overload taking as many arguments as given which accepts the most
+    /// of them before one it cannot be given, along with that argument's index.
+    pub fn get_closest_overload(&self, args: &[Type]) -> Option<(&Signature, usize)> {
+        self.overloads
+            .iter()
+            .filter(|signature| signature.accepts_count(args.len()))
+            .filter_map(|signature| Some((signature, signature.infer(args).err()?)))
+            // `max_by_key` picks the last of equals, so this prefers the first.
+            .rev()
+            .max_by_key(|(_, index)| *index)
+    }
+
+    pub fn get_return_type(&self, args: Vec<Type>) -> Type {
+        match self.get_overload(&args) {
+            Some(signature) => signature.get_return_type(args),
+            None => Type::Error,
         }
     }
+}
 
-    pub fn get_arg_type(&self, args: Vec<Type>) -> Type {
-        let mut found: Option<Type> = None;
-        for (index, FunctionArg(_, expected)) in self.args.iter().enumerate() {
-            let actual = args.get(index).unwrap();
-            match expected {
-                GenericType::Named(ref t) => {}
-                GenericType::TypeParam { super_ } => {
-                    let found_type = if actual.is_assignable_to(super_) {
-                        actual.clone()
-                    } else {
-                        Type::Error
-                    };
-                    let new_type = match found {
-                        Some(ref found_type) => found_type.get_shared_super_type(&found_type),
-                        None => found_type,
-                    };
-                    let found = Some(new_type);
-                }
-                GenericType::GenericArray { inner_super_ } => {
-                    if let Type::Array(ref t) = actual {
-                        if t.is_assignable_to(inner_super_) {
-                            return t.as_ref().clone();
-                        } else {
-                            return Type::Error;
-                        }
-                    }
-                }
-                GenericType::GenericOption { inner_super_ } => {
-                    if let Type::Option(ref t) = actual {
-                        if t.is_assignable_to(inner_super_) {
-                            return t.as_ref().clone();
-                        } else {
-                            return Type::Error;
-                        }
-                    }
+impl Signature {
+    pub fn get_arg(&self, index: usize) -> Option<&FunctionArg> {
+        match self.args.last() {
+            Some(last) if last.2 == ArgKind::Rest && index >= self.args.len() => Some(last),
+            _ => self.args.get(index),
+        }
+    }
+
+    pub fn get_required_args(&self) -> Vec<&FunctionArg> {
+        self.args
+            .iter()
+            .filter(|arg| arg.2 == ArgKind::Required)
+            .collect()
+    }
+
+    pub fn accepts_count(&self, count: usize) -> bool {
+        count >= self.get_required_args().len()
+            && (count <= self.args.len()
+                || self.args.last().is_some_and(|arg| arg.2 == ArgKind::Rest))
+    }
+
+    pub fn matches(&self, args: &[Type]) -> bool {
+        self.accepts_count(args.len()) && self.infer(args).is_ok()
+    }
+
+    /// Infers the type parameter from the arguments, or returns the index of
+    /// the first argument which cannot be passed to this signature.
+    pub fn infer(&self, args: &[Type]) -> Result<Type, usize> {
+        let mut found = None;
+        for (index, actual) in args.iter().enumerate() {
+            if let Some(FunctionArg(_, expected, _)) = self.get_arg(index) {
+                if !expected.unify(actual, &mut found) {
+                    return Err(index);
                 }
             }
         }
-        match found {
-            Some(t) => t,
-            None => Type::Any,
-        }
+        Ok(found.unwrap_or_else(|| self.get_type_param_bound().unwrap_or(Type::Any)))
+    }
+
+    pub fn get_type_param_bound(&self) -> Option<Type> {
+        self.args
+            .iter()
+            .map(|arg| &arg.1)
+            .chain([&self.return_type])
+            .find_map(|ty| ty.get_type_param_bound())
     }
 
     pub fn get_return_type(&self, args: Vec<Type>) -> Type {
-        let arg_type = self.get_arg_type(args);
-        self.get_type(arg_type)
+        match self.infer(&args) {
+            Ok(arg) => self.return_type.substitute(&arg),
+            Err(_) => Type::Error,
+        }
     }
 }
 
-impl Display for Function {
+impl Display for Signature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut args = vec![];
-        for arg in &self.args {
-            args.push(format!("{}: {}", arg.0, arg.1));
+        for FunctionArg(name, ty, kind) in &self.args {
+            args.push(match kind {
+                ArgKind::Required => format!("{}: {}", name, ty),
+                ArgKind::Optional => format!("{}?: {}", name, ty),
+                ArgKind::Rest => format!("...{}: {}", name, ty),
+            });
+        }
+        write!(f, "({}) -> {}", args.join(", "), self.return_type)?;
+        match self.get_type_param_bound() {
+            Some(Type::Any) | None => Ok(()),
+            Some(bound) => write!(f, " where T: {}", bound),
         }
-        write!(f, "({}) -> {}", args.join(", "), self.return_type)
     }
 }
 
 pub fn get_functions() -> HashMap<String, Function> {
-    return get_count_functions();
+    let mut functions = get_count_functions();
+    functions.extend(get_array_functions());
+    functions.extend(get_crypto_functions());
+    functions.extend(get_duration_functions());
+    functions.extend(get_geo_functions());
+    functions.extend(get_http_functions());
+    functions.extend(get_math_functions());
+    functions.extend(get_meta_functions());
+    functions.extend(get_object_functions());
+    functions.extend(get_parse_functions());
+    functions.extend(get_rand_functions());
+    functions.extend(get_record_functions());
+    functions.extend(get_search_functions());
+    functions.extend(get_session_functions());
+    functions.extend(get_sleep_functions());
+    functions.extend(get_string_functions());
+    functions.extend(get_time_functions());
+    functions.extend(get_type_functions());
+    functions.extend(get_vector_functions());
+    functions
 }
@@ -1,10 +1,25 @@
-use super::field::Field;
+use tower_lsp::lsp_types::Location;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+use super::{field::Field, type_::Type};
+
+#[derive(Clone, Debug)]
 pub struct Object {
     pub fields: Vec<Field>,
+    /// Where the table is declared by a `DEFINE TABLE` statement, if known.
+    pub location: Option<Location>,
+    /// Whether the object can hold fields besides `fields`, as a plain
+    /// `object` of unknown shape does.
+    pub is_flexible: bool,
 }
 
+impl PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields && self.is_flexible == other.is_flexible
+    }
+}
+
+impl Eq for Object {}
+
 impl Object {
     pub fn get_field(&self, name: &str) -> Option<&Field> {
         self.fields.iter().find(|f| f.name == name)
@@ -12,16 +27,60 @@ impl Object {
 }
 
 impl Object {
+    /// Whether `other` fits this object, checked as object literals are:
+    /// every field of `other` must be declared here with a type it fits, and
+    /// every required field here must be set.
     pub fn is_assignable_to(&self, other: &Object) -> bool {
+        let declared = other
+            .fields
+            .iter()
+            .all(|field| match self.get_field(&field.name) {
+                Some(self_field) => self_field.ty.is_assignable_to(&field.ty),
+                None => self.is_flexible,
+            });
+        let set = self.fields.iter().all(|field| {
+            !field.is_required || other.is_flexible || other.get_field(&field.name).is_some()
+        });
+        declared && set
+    }
+
+    /// The object both `self` and `other` fit, where the fields only one of
+    /// them has are optional.
+    pub fn get_shared_super_type(&self, other: &Object) -> Object {
+        let optional = |field: &Field| Field {
+            name: field.name.clone(),
+            ty: field.ty.get_shared_super_type(&Type::Null),
+            is_required: false,
+            location: None,
+            owner: None,
+        };
+        let mut fields = vec![];
         for field in &self.fields {
-            if let Some(other_field) = other.get_field(&field.name) {
-                if !field.ty.is_assignable_to(&other_field.ty) {
-                    return false;
+            fields.push(match other.get_field(&field.name) {
+                Some(other_field) => {
+                    let ty = field.ty.get_shared_super_type(&other_field.ty);
+                    Field {
+                        name: field.name.clone(),
+                        is_required: field.is_required
+                            && other_field.is_required
+                            && !matches!(ty, Type::Option(_)),
+                        ty,
+                        location: None,
+                        owner: None,
+                    }
                 }
-            } else {
-                return false;
+                None => optional(field),
+            });
+        }
+        for field in &other.fields {
+            if self.get_field(&field.name).is_none() {
+                fields.push(optional(field));
             }
         }
-        true
+        Object {
+            fields,
+            location: None,
+            is_flexible: self.is_flexible || other.is_flexible,
+        }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use super::{
-    functions::{get_functions, Function},
+    functions::{get_functions, Function, AGGREGATE_FUNCTIONS},
     object::Object,
     type_::Type,
 };
@@ -12,12 +12,22 @@ pub struct ScopedItems {
     pub scoped_table: Object,
     pub variables: HashMap<String, Type>,
     pub functions: HashMap<String, Function>,
+    /// Whether the projections of a `SELECT` with a `GROUP` clause are being
+    /// read, where aggregate functions are given the values of a whole group.
+    pub is_grouped: bool,
+    /// Whether the body of a `FOR` loop is being read, where `BREAK` and
+    /// `CONTINUE` can be used.
+    pub is_in_loop: bool,
 }
 
 impl Default for ScopedItems {
     fn default() -> Self {
         let table_definitions = HashMap::new();
-        let scoped_table = Object { fields: vec![] };
+        let scoped_table = Object {
+            fields: vec![],
+            location: None,
+            is_flexible: false,
+        };
         let variables = HashMap::new();
         let mut functions = get_functions();
         Self {
@@ -25,6 +35,39 @@ impl Default for ScopedItems {
             scoped_table,
             variables,
             functions,
+            is_grouped: false,
+            is_in_loop: false,
         }
     }
 }
+
+impl ScopedItems {
+    /// Whether a call to `name` aggregates a group of records, in which case
+    /// each argument is collected into an array of its values.
+    pub fn is_aggregate_call(&self, name: &str) -> bool {
+        self.is_grouped && AGGREGATE_FUNCTIONS.contains(&name)
+    }
+
+    /// The type a call to `name` is given for an argument of type `ty`. Empty
+    /// values are left out of the arrays collected by aggregate functions.
+    pub fn get_arg_type(&self, name: &str, ty: Type) -> Type {
+        if !self.is_aggregate_call(name) {
+            return ty;
+        }
+        match ty {
+            Type::Option(inner) => Type::Array(inner),
+            ty => Type::Array(Box::new(ty)),
+        }
+    }
+
+    /// Follows a `record<table>` link to the definition of its table, so that
+    /// fields can be accessed through it. Other types are returned unchanged.
+    pub fn dereference(&self, ty: Type) -> Type {
+        if let Type::Record(table) = &ty {
+            if let Some(definition) = self.table_definitions.get(table) {
+                return Type::Object(definition.clone());
+            }
+        }
+        ty
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::object::Object;
+use super::{field::Field, object::Object};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Type {
@@ -43,6 +43,48 @@ impl Display for Type {
 }
 
 impl Type {
+    /// The field `name` of an object type, looking through `option<...>`.
+    pub fn get_field(&self, name: &str) -> Option<&Field> {
+        match self {
+            Type::Object(obj) => obj.get_field(name),
+            Type::Option(inner) => inner.get_field(name),
+            _ => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Type::Int | Type::Float | Type::Decimal | Type::Number)
+    }
+
+    pub fn is_string(&self) -> bool {
+        *self == Type::String
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, Type::Array(_))
+    }
+
+    /// An array of numbers, as the operands of KNN are.
+    pub fn is_vector(&self) -> bool {
+        match self {
+            Type::Array(inner) => matches!(inner.unwrap_option(), Type::Any) || inner.is_numeric(),
+            _ => false,
+        }
+    }
+
+    /// Whether values of the two types can be compared with each other.
+    pub fn is_comparable_to(&self, other: &Type) -> bool {
+        self.is_assignable_to(other) || other.is_assignable_to(self)
+    }
+
+    /// The type inside any number of `option<...>`s.
+    pub fn unwrap_option(&self) -> &Type {
+        match self {
+            Type::Option(inner) => inner.unwrap_option(),
+            _ => self,
+        }
+    }
+
     pub fn is_assignable_to(&self, other: &Type) -> bool {
         if self == other {
             return true;
@@ -68,6 +110,25 @@ impl Type {
         }
     }
 
+    /// Whether a value of this type could ever be cast to `to`.
+    pub fn can_cast_to(&self, to: &Type) -> bool {
+        match (self, to) {
+            (Type::Any | Type::Error, _) | (_, Type::Any | Type::Error) => true,
+            _ if to.is_assignable_to(self) => true,
+            (Type::Option(inner), _) => inner.can_cast_to(to),
+            (_, Type::Option(inner)) => self.can_cast_to(inner),
+            // Everything has a string form, and strings are parsed into
+            // whatever they are cast to.
+            (_, Type::String) | (Type::String, _) => {
+                !matches!(to, Type::Null | Type::Array(_) | Type::Object(_))
+            }
+            (from, to) if from.is_numeric() && to.is_numeric() => true,
+            (Type::Array(from), Type::Array(to)) => from.can_cast_to(to),
+            (Type::Object(_), Type::Object(_)) => true,
+            _ => false,
+        }
+    }
+
     pub fn get_shared_super_type(&self, other: &Type) -> Type {
         if self == other {
             return self.clone();
@@ -85,20 +146,9 @@ impl Type {
             (Type::Any, _) => Type::Any,
             (_, Type::Any) => Type::Any,
             (Type::Array(a), Type::Array(b)) => Type::Array(Box::new(a.get_shared_super_type(b))),
-            (Type::Object(a), Type::Object(b)) => {
-                if a == b {
-                    Type::Object(a.clone())
-                } else {
-                    Type::Error
-                }
-            }
-            (Type::Record(a), Type::Record(b)) => {
-                if a == b {
-                    Type::Record(a.clone())
-                } else {
-                    Type::Error
-                }
-            }
+            (Type::Object(a), Type::Object(b)) => Type::Object(a.get_shared_super_type(b)),
+            // There is no type for records of several tables.
+            (Type::Record(_), Type::Record(_)) => Type::Any,
             (Type::Number, Type::Int) => Type::Number,
             (Type::Number, Type::Float) => Type::Number,
             (Type::Number, Type::Decimal) => Type::Number,
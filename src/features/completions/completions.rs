@@ -3,7 +3,10 @@ use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, CompletionParams}
 
 use crate::{
     features::completions::has_completions::HasCompletionItems,
-    lexer::{lexer::lexer, token::Token},
+    lexer::{
+        lexer::{lexer, remove_comments},
+        token::Token,
+    },
     ls::backend::Backend,
     parser::parser::parser,
     util::range::span_to_range,
@@ -16,11 +19,12 @@ pub async fn get_completions(backend: &Backend, _params: CompletionParams) -> Ve
     let text = rope.value().to_string();
     let (tokens, _) = lexer().parse(text.as_str()).into_output_errors();
     if let Some(tokens) = tokens {
+        let tokens = remove_comments(tokens);
         let mut scoped_items = backend.state.lock().await;
         let parser_result = parser().parse_with_state(
             tokens
                 .as_slice()
-                .spanned((rope.len_chars()..rope.len_chars()).into()),
+                .spanned((rope.len_bytes()..rope.len_bytes()).into()),
             &mut scoped_items,
         );
         let (ast, parse_errs) = parser_result.into_output_errors();
@@ -2,9 +2,16 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Position};
 
 use crate::{
-    ast::expr::{access::Access, parser::Expression, types::Typed},
+    ast::expr::{
+        access::{Access, Graph},
+        parser::Expression,
+        types::Typed,
+    },
     declarations::{scoped_item::ScopedItems, type_::Type},
-    features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
+    features::completions::{
+        has_completions::{HasCompletionItems, HasCompletionItemsForType},
+        table_name::get_completion_items_for_table_name,
+    },
     util::range::span_to_range,
 };
 
@@ -46,6 +53,12 @@ impl HasCompletionItemsForType for Expression {
                 completions.extend(get_completions_for_function(scope));
                 completions
             }
+            Expression::Access { expr, .. }
+                if span_to_range(&expr.1, rope).unwrap().end >= position =>
+            {
+                expr.0
+                    .get_completion_items_for_type(scope, position, rope, &Type::Any)
+            }
             Expression::Access { expr, access } => match access.0.as_ref() {
                 Access::Property(_) => {
                     let mut ty = expr.0.get_type(scope);
@@ -54,7 +67,7 @@ impl HasCompletionItemsForType for Expression {
                         ty = *inner_ty.clone();
                         array_nest_count += 1;
                     }
-                    if let Type::Object(obj) = ty {
+                    if let Type::Object(obj) = scope.dereference(ty) {
                         obj.fields
                             .into_iter()
                             .map(|x| {
@@ -74,11 +87,16 @@ impl HasCompletionItemsForType for Expression {
                         vec![]
                     }
                 }
-                Access::Index(_) => vec![],
+                Access::Index(_) | Access::All => vec![],
+                Access::Graph(graph) => get_completions_for_graph(graph, scope, position, rope),
             },
+            Expression::Graph(graph) => get_completions_for_graph(graph, scope, position, rope),
             Expression::Binary { left, op, right } => {
                 get_completions_for_binary(scope, position, rope, type_, left, op, right)
             }
+            Expression::Unary { expr, .. } | Expression::Cast { expr, .. } => expr
+                .0
+                .get_completion_items_for_type(scope, position, rope, &Type::Any),
             Expression::Variable(_) => get_completion_for_variable(scope),
             Expression::CodeBlock(block) => block.get_completion_items(scope, position, rope),
             Expression::Call { name, args } => {
@@ -100,3 +118,25 @@ impl HasCompletionItemsForType for Expression {
         }
     }
 }
+
+/// Completions for a graph traversal step: the fields of the traversed table
+/// inside its `[WHERE ...]` condition, table names anywhere else.
+fn get_completions_for_graph(
+    graph: &Graph,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Vec<CompletionItem> {
+    if let Some(condition) = &graph.condition {
+        let condition_range = span_to_range(&condition.1, rope).unwrap();
+        if condition_range.start <= position && position <= condition_range.end {
+            return condition.0.get_completion_items_for_type(
+                &graph.get_condition_scope(scope),
+                position,
+                rope,
+                &Type::Bool,
+            );
+        }
+    }
+    get_completion_items_for_table_name(scope)
+}
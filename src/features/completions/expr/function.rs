@@ -9,7 +9,10 @@ pub fn get_completions_for_function(scope: &ScopedItems) -> Vec<CompletionItem>
     for (name, def) in &scope.functions {
         let mut insert_text = name.clone();
         insert_text.push('(');
-        for (i, arg) in def.args.iter().enumerate() {
+        let Some(signature) = def.overloads.first() else {
+            continue;
+        };
+        for (i, arg) in signature.get_required_args().iter().enumerate() {
             if i != 0 {
                 insert_text.push_str(", ");
             }
@@ -27,7 +30,10 @@ pub fn get_completions_for_function(scope: &ScopedItems) -> Vec<CompletionItem>
                     value: doc,
                 })
             }),
-            detail: Some(format!("{}{}", name, def)),
+            detail: Some(match def.overloads.len() {
+                1 => format!("{}{}", name, signature),
+                n => format!("{}{} (+{} overloads)", name, signature, n - 1),
+            }),
             ..Default::default()
         });
     }
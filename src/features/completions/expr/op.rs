@@ -2,7 +2,7 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{CompletionItem, Position};
 
 use crate::{
-    ast::expr::{op::BinaryOperator, parser::Expression},
+    ast::expr::{op::BinaryOperator, parser::Expression, types::Typed},
     declarations::{scoped_item::ScopedItems, type_::Type},
     features::completions::has_completions::HasCompletionItemsForType,
     util::{range::span_to_range, span::Spanned},
@@ -17,19 +17,60 @@ pub fn get_completions_for_binary(
     op: &Spanned<BinaryOperator>,
     right: &Box<Spanned<Expression>>,
 ) -> Vec<CompletionItem> {
+    // Operators which return one of their operands expect both to be of
+    // the result type.
+    let passes_through = matches!(
+        op.0,
+        BinaryOperator::And
+            | BinaryOperator::Or
+            | BinaryOperator::NullCoalesce
+            | BinaryOperator::Truthy
+    );
     let left_range = span_to_range(&left.1, rope).unwrap();
     if left_range.start <= position && position <= left_range.end {
+        let expected = if passes_through { type_ } else { &Type::Any };
         return left
             .as_ref()
             .0
-            .get_completion_items_for_type(scope, position, rope, type_);
+            .get_completion_items_for_type(scope, position, rope, expected);
     }
     let right_range = span_to_range(&right.1, rope).unwrap();
     if right_range.start <= position && position <= right_range.end {
+        let expected = if passes_through {
+            type_.clone()
+        } else {
+            get_right_operand_type(&op.0, left.0.get_type(scope))
+        };
         return right
             .as_ref()
             .0
-            .get_completion_items_for_type(scope, position, rope, type_);
+            .get_completion_items_for_type(scope, position, rope, &expected);
     }
     vec![]
 }
+
+/// The type the right operand of `op` is compared against, given the type of
+/// the left one.
+fn get_right_operand_type(op: &BinaryOperator, left: Type) -> Type {
+    let left = left.unwrap_option().clone();
+    match op {
+        BinaryOperator::Equals
+        | BinaryOperator::NotEquals
+        | BinaryOperator::LessThan
+        | BinaryOperator::LessThanOrEqual
+        | BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterThanOrEqual
+        | BinaryOperator::ContainsAll
+        | BinaryOperator::ContainsAny
+        | BinaryOperator::ContainsNone
+        | BinaryOperator::AllInside
+        | BinaryOperator::AnyInside
+        | BinaryOperator::NoneInside => left,
+        BinaryOperator::Contains | BinaryOperator::ContainsNot => match left {
+            Type::Array(item) => *item,
+            _ => Type::Any,
+        },
+        BinaryOperator::Inside | BinaryOperator::NotInside => Type::Array(Box::new(left)),
+        _ => Type::Any,
+    }
+}
@@ -0,0 +1,119 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Position};
+
+use crate::{
+    ast::statement::crud::data::{Assignment, Data},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::has_completions::HasCompletionItemsForType,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl HasCompletionItemsForType for Data {
+    fn get_completion_items_for_type(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+        type_: &Type,
+    ) -> Vec<CompletionItem> {
+        match self {
+            Data::Content(Some(content))
+            | Data::Replace(Some(content))
+            | Data::Merge(Some(content)) => {
+                let content_range = span_to_range(&content.1, rope).unwrap();
+                if content_range.start <= position && position <= content_range.end {
+                    return content
+                        .0
+                        .get_completion_items_for_type(scope, position, rope, type_);
+                }
+                vec![]
+            }
+            Data::Patch(Some(patch)) => {
+                let patch_range = span_to_range(&patch.1, rope).unwrap();
+                if patch_range.start <= position && position <= patch_range.end {
+                    return patch.0.get_completion_items_for_type(
+                        scope,
+                        position,
+                        rope,
+                        &Type::Array(Box::new(Type::Any)),
+                    );
+                }
+                vec![]
+            }
+            Data::Content(None) | Data::Replace(None) | Data::Merge(None) | Data::Patch(None) => {
+                vec![]
+            }
+            Data::Set(assignments) => {
+                get_completion_items_for_assignments(assignments, scope, position, rope, type_)
+            }
+            Data::Unset(fields) => {
+                for path in fields {
+                    let mut ty = type_.clone();
+                    for (name, span) in path {
+                        let range = span_to_range(span, rope).unwrap();
+                        if range.start <= position && position <= range.end {
+                            return get_completion_items_for_fields(&ty);
+                        }
+                        ty = match ty.get_field(name) {
+                            Some(field) => field.ty.clone(),
+                            None => Type::Any,
+                        };
+                    }
+                }
+                vec![]
+            }
+        }
+    }
+}
+
+/// Completions for the field paths and values of `assignments` to a record
+/// of type `type_`.
+pub fn get_completion_items_for_assignments(
+    assignments: &[Spanned<Assignment>],
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+    type_: &Type,
+) -> Vec<CompletionItem> {
+    for (assignment, _) in assignments {
+        let mut ty = type_.clone();
+        for (name, span) in &assignment.field {
+            let range = span_to_range(span, rope).unwrap();
+            if range.start <= position && position <= range.end {
+                return get_completion_items_for_fields(&ty);
+            }
+            ty = match ty.get_field(name) {
+                Some(field) => field.ty.clone(),
+                None => Type::Any,
+            };
+        }
+        let value = &assignment.value;
+        let value_range = span_to_range(&value.1, rope).unwrap();
+        if value_range.start <= position && position <= value_range.end {
+            return value
+                .0
+                .get_completion_items_for_type(scope, position, rope, &ty);
+        }
+    }
+    vec![]
+}
+
+pub fn get_completion_items_for_fields(ty: &Type) -> Vec<CompletionItem> {
+    let mut ty = ty;
+    while let Type::Option(inner) = ty {
+        ty = inner;
+    }
+    match ty {
+        Type::Object(obj) => obj
+            .fields
+            .iter()
+            .map(|field| CompletionItem {
+                label: field.name.clone(),
+                kind: Some(CompletionItemKind::FIELD),
+                detail: Some(format!("{}", field.ty)),
+                ..Default::default()
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
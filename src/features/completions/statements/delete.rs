@@ -2,12 +2,8 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{CompletionItem, Position};
 
 use crate::{
-    ast::statement::crud::delete::DeleteStatement,
-    declarations::scoped_item::ScopedItems,
-    features::completions::{
-        has_completions::HasCompletionItems, table_name::get_completion_items_for_table_name,
-    },
-    util::range::span_to_range,
+    ast::statement::crud::delete::DeleteStatement, declarations::scoped_item::ScopedItems,
+    features::completions::has_completions::HasCompletionItems, util::range::span_to_range,
 };
 
 impl HasCompletionItems for DeleteStatement {
@@ -18,12 +14,12 @@ impl HasCompletionItems for DeleteStatement {
         rope: &Rope,
     ) -> Vec<CompletionItem> {
         let mut scope = scope.clone();
-        if let Some(table) = &self.table {
-            let name_range = span_to_range(&table.1, rope).unwrap();
-            if name_range.start <= position && position <= name_range.end {
-                return get_completion_items_for_table_name(&scope);
+        if let Some(target) = &self.target {
+            let target_range = span_to_range(&target.1, rope).unwrap();
+            if target_range.start <= position && position <= target_range.end {
+                return target.0.get_completion_items(&scope, position, rope);
             }
-            match scope.table_definitions.get(&table.0) {
+            match target.0.get_definition(&scope) {
                 Some(obj) => {
                     for field in &obj.fields {
                         scope.scoped_table.fields.retain(|f| f.name != field.name);
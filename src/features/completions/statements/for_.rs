@@ -0,0 +1,38 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::statement::for_::ForStatement,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
+    util::range::span_to_range,
+};
+
+impl HasCompletionItems for ForStatement {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        let iterable_range = span_to_range(&self.iterable.1, rope).unwrap();
+        if iterable_range.start <= position && position <= iterable_range.end {
+            return self.iterable.0.get_completion_items_for_type(
+                scope,
+                position,
+                rope,
+                &Type::Array(Box::new(Type::Any)),
+            );
+        }
+        let body_range = span_to_range(&self.body.1, rope).unwrap();
+        if body_range.start <= position && position <= body_range.end {
+            return self.body.0.get_completion_items_for_type(
+                &self.get_body_scope(scope),
+                position,
+                rope,
+                &Type::Any,
+            );
+        }
+        vec![]
+    }
+}
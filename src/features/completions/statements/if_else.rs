@@ -0,0 +1,28 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::statement::if_else::IfElseStatement,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
+    util::range::span_to_range,
+};
+
+impl HasCompletionItems for IfElseStatement {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        for expr in self.expressions() {
+            let range = span_to_range(&expr.1, rope).unwrap();
+            if range.start <= position && position <= range.end {
+                return expr
+                    .0
+                    .get_completion_items_for_type(scope, position, rope, &Type::Any);
+            }
+        }
+        vec![]
+    }
+}
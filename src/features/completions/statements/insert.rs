@@ -0,0 +1,105 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        statement::crud::{
+            insert::{InsertStatement, InsertValues},
+            output::Output,
+        },
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::{
+        has_completions::{HasCompletionItems, HasCompletionItemsForType},
+        table_name::get_completion_items_for_table_name,
+    },
+    util::range::span_to_range,
+};
+
+use super::data::{get_completion_items_for_assignments, get_completion_items_for_fields};
+
+impl HasCompletionItems for InsertStatement {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        let mut scope = scope.clone();
+        let Some(table) = &self.table else {
+            return get_completion_items_for_table_name(&scope);
+        };
+        let name_range = span_to_range(&table.1, rope).unwrap();
+        if name_range.start <= position && position <= name_range.end {
+            return get_completion_items_for_table_name(&scope);
+        }
+        let ty = match scope.table_definitions.get(&table.0) {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj.clone())
+            }
+            None => Type::Any,
+        };
+        if let Some(values) = &self.values {
+            let values_range = span_to_range(&values.1, rope).unwrap();
+            if values_range.start <= position && position <= values_range.end {
+                return match &values.0 {
+                    InsertValues::Content(content) => {
+                        let ty = match content.0 {
+                            Expression::Array(_) => Type::Array(Box::new(ty)),
+                            _ => ty,
+                        };
+                        content
+                            .0
+                            .get_completion_items_for_type(&scope, position, rope, &ty)
+                    }
+                    InsertValues::Values { fields, rows } => {
+                        for (_, span) in fields {
+                            let range = span_to_range(span, rope).unwrap();
+                            if range.start <= position && position <= range.end {
+                                return get_completion_items_for_fields(&ty);
+                            }
+                        }
+                        for (row, _) in rows {
+                            for (value, field) in row.iter().zip(fields) {
+                                let range = span_to_range(&value.1, rope).unwrap();
+                                if range.start <= position && position <= range.end {
+                                    let ty = match ty.get_field(&field.0) {
+                                        Some(field) => field.ty.clone(),
+                                        None => Type::Any,
+                                    };
+                                    return value.0.get_completion_items_for_type(
+                                        &scope, position, rope, &ty,
+                                    );
+                                }
+                            }
+                        }
+                        vec![]
+                    }
+                };
+            }
+        }
+        if let Some(assignments) = &self.on_duplicate {
+            let mut scope = scope.clone();
+            scope.variables.insert("input".to_string(), ty.clone());
+            let completions =
+                get_completion_items_for_assignments(assignments, &scope, position, rope, &ty);
+            if !completions.is_empty() {
+                return completions;
+            }
+        }
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                let projection_range = span_to_range(&projection.1, rope).unwrap();
+                if projection_range.start <= position && position <= projection_range.end {
+                    return projection.0.get_completion_items(&scope, position, rope);
+                }
+            }
+        }
+        vec![]
+    }
+}
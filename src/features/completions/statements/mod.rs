@@ -1,9 +1,16 @@
 pub mod create;
+pub mod data;
 pub mod define;
 pub mod delete;
+pub mod for_;
+pub mod if_else;
+pub mod insert;
 pub mod let_;
 pub mod projection;
+pub mod relate;
 pub mod select;
 pub mod statement;
+pub mod target;
 pub mod transform;
 pub mod update;
+pub mod upsert;
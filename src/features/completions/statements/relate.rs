@@ -0,0 +1,71 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::statement::crud::{output::Output, relate::RelateStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::{
+        has_completions::{HasCompletionItems, HasCompletionItemsForType},
+        table_name::get_completion_items_for_table_name,
+    },
+    util::range::span_to_range,
+};
+
+impl HasCompletionItems for RelateStatement {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        let mut scope = scope.clone();
+        for node in [&self.from, &self.to].into_iter().flatten() {
+            let node_range = span_to_range(&node.1, rope).unwrap();
+            if node_range.start <= position && position <= node_range.end {
+                return node.0.get_completion_items(&scope, position, rope);
+            }
+        }
+        match (&self.from, &self.edge) {
+            (_, Some(edge)) => {
+                let edge_range = span_to_range(&edge.1, rope).unwrap();
+                if edge_range.start <= position && position <= edge_range.end {
+                    return get_completion_items_for_table_name(&scope);
+                }
+            }
+            // Nothing has been written after the first arrow yet.
+            (Some(from), None) => {
+                if span_to_range(&from.1, rope).unwrap().end < position {
+                    return get_completion_items_for_table_name(&scope);
+                }
+            }
+            (None, None) => {}
+        }
+        let ty = match self.get_edge_definition(&scope) {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj)
+            }
+            None => Type::Any,
+        };
+        if let Some(data) = &self.data {
+            let data_range = span_to_range(&data.1, rope).unwrap();
+            if data_range.start <= position && position <= data_range.end {
+                return data
+                    .0
+                    .get_completion_items_for_type(&scope, position, rope, &ty);
+            }
+        }
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                let projection_range = span_to_range(&projection.1, rope).unwrap();
+                if projection_range.start <= position && position <= projection_range.end {
+                    return projection.0.get_completion_items(&scope, position, rope);
+                }
+            }
+        }
+        vec![]
+    }
+}
@@ -4,9 +4,7 @@ use tower_lsp::lsp_types::{CompletionItem, Position};
 use crate::{
     ast::{expr::types::Typed, statement::crud::select::SelectStatement},
     declarations::{field::Field, scoped_item::ScopedItems, type_::Type},
-    features::completions::{
-        has_completions::HasCompletionItems, table_name::get_completion_items_for_table_name,
-    },
+    features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
     util::range::span_to_range,
 };
 
@@ -18,15 +16,20 @@ impl HasCompletionItems for SelectStatement {
         rope: &Rope,
     ) -> Vec<CompletionItem> {
         let mut scope = scope.clone();
-        if let Some(from) = &self.from {
-            if let Some(table) = scope.table_definitions.get(&from.0) {
-                scope.scoped_table = table.clone();
+        for from in &self.from {
+            let from_range = span_to_range(&from.1, rope).unwrap();
+            if from_range.start <= position && position <= from_range.end {
+                return from.0.get_completion_items(&scope, position, rope);
             }
-            let name_range = span_to_range(&from.1, rope).unwrap();
-            if name_range.start <= position && position <= name_range.end {
-                return get_completion_items_for_table_name(&scope);
+        }
+        for from in &self.from {
+            if let Some(table) = from.0.get_definition(&scope) {
+                for field in &table.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
             }
-        };
+        }
         for projection in &self.projections {
             let projection_range = span_to_range(&projection.1, rope).unwrap();
             if let Some(alias) = &projection.0.alias {
@@ -39,12 +42,22 @@ impl HasCompletionItems for SelectStatement {
                         _ => true,
                     },
                     ty,
+                    location: None,
+                    owner: None,
                 });
             }
             if projection_range.start <= position && position <= projection_range.end {
                 return projection.0.get_completion_items(&scope, position, rope);
             }
         }
+        for omit in &self.omit {
+            let omit_range = span_to_range(&omit.1, rope).unwrap();
+            if omit_range.start <= position && position <= omit_range.end {
+                return omit
+                    .0
+                    .get_completion_items_for_type(&scope, position, rope, &Type::Any);
+            }
+        }
         for transform in &self.transforms {
             let transform_range = span_to_range(&transform.1, rope).unwrap();
             if transform_range.start <= position && position <= transform_range.end {
@@ -1,13 +1,32 @@
 use ropey::Rope;
-use tower_lsp::lsp_types::{CompletionItem, Position};
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Position};
 
 use crate::{
-    ast::statement::statement::Statement,
+    ast::{expr::parser::Expression, statement::statement::Statement},
     declarations::{scoped_item::ScopedItems, type_::Type},
     features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
+    lexer::keyword::Keyword,
     util::range::span_to_range,
 };
 
+/// Keywords which can start a statement.
+const STATEMENT_KEYWORDS: [Keyword; 14] = [
+    Keyword::Select,
+    Keyword::Create,
+    Keyword::Update,
+    Keyword::Upsert,
+    Keyword::Delete,
+    Keyword::Insert,
+    Keyword::Relate,
+    Keyword::Define,
+    Keyword::Let,
+    Keyword::Return,
+    Keyword::For,
+    Keyword::Break,
+    Keyword::Continue,
+    Keyword::Throw,
+];
+
 impl HasCompletionItems for Statement {
     fn get_completion_items(
         &self,
@@ -18,7 +37,10 @@ impl HasCompletionItems for Statement {
         match &self {
             Statement::Create(create) => create.get_completion_items(scope, position, rope),
             Statement::Update(update) => update.get_completion_items(scope, position, rope),
+            Statement::Upsert(upsert) => upsert.get_completion_items(scope, position, rope),
             Statement::Delete(delete) => delete.get_completion_items(scope, position, rope),
+            Statement::Insert(insert) => insert.get_completion_items(scope, position, rope),
+            Statement::Relate(relate) => relate.get_completion_items(scope, position, rope),
             Statement::Select(select) => select.get_completion_items(scope, position, rope),
             Statement::Return(expr) => {
                 let range = span_to_range(&expr.1, rope).unwrap();
@@ -31,7 +53,45 @@ impl HasCompletionItems for Statement {
             }
             Statement::Define(define) => define.0.get_completion_items(scope, position, rope),
             Statement::Let(let_) => let_.get_completion_items(scope, position, rope),
-            Statement::Invalid => vec![],
+            Statement::IfElse(if_else) => if_else.get_completion_items(scope, position, rope),
+            Statement::For(for_) => for_.get_completion_items(scope, position, rope),
+            Statement::Throw(expr) => {
+                let range = span_to_range(&expr.1, rope).unwrap();
+                if range.start <= position && position <= range.end {
+                    return expr
+                        .0
+                        .get_completion_items_for_type(scope, position, rope, &Type::Any);
+                }
+                vec![]
+            }
+            Statement::Expression(expr) => {
+                let range = span_to_range(&expr.1, rope).unwrap();
+                if !(range.start <= position && position <= range.end) {
+                    return vec![];
+                }
+                let mut items =
+                    expr.0
+                        .get_completion_items_for_type(scope, position, rope, &Type::Any);
+                // A lone word may as well be the start of a statement.
+                if let Expression::Identifier(_) = expr.0 {
+                    items.extend(statement_keyword_items());
+                }
+                items
+            }
+            Statement::Break | Statement::Continue => vec![],
+            // Whatever was typed doesn't start a statement yet.
+            Statement::Invalid => statement_keyword_items(),
         }
     }
 }
+
+fn statement_keyword_items() -> Vec<CompletionItem> {
+    STATEMENT_KEYWORDS
+        .iter()
+        .map(|kw| CompletionItem {
+            label: kw.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..Default::default()
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::target::Target,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::{
+        has_completions::{HasCompletionItems, HasCompletionItemsForType},
+        table_name::get_completion_items_for_table_name,
+    },
+    util::range::span_to_range,
+};
+
+impl HasCompletionItems for Target {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        if let Some(table) = self.table() {
+            let name_range = span_to_range(&table.1, rope).unwrap();
+            if name_range.start <= position && position <= name_range.end {
+                return get_completion_items_for_table_name(scope);
+            }
+        }
+        for expr in self.expressions() {
+            let range = span_to_range(&expr.1, rope).unwrap();
+            if range.start <= position && position <= range.end {
+                return expr
+                    .0
+                    .get_completion_items_for_type(scope, position, rope, &Type::Any);
+            }
+        }
+        vec![]
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     ast::statement::transform::Transform,
     declarations::type_::Type,
     features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
+    util::range::span_to_range,
 };
 
 impl HasCompletionItems for Transform {
@@ -13,12 +14,19 @@ impl HasCompletionItems for Transform {
         position: tower_lsp::lsp_types::Position,
         rope: &ropey::Rope,
     ) -> Vec<CompletionItem> {
-        let expr = match self {
-            Transform::Where(Some((e, _))) => e,
-            Transform::Limit(Some((e, _))) => e,
-            Transform::Skip(Some((e, _))) => e,
-            _ => return vec![],
-        };
-        expr.get_completion_items_for_type(scope, position, rope, &Type::Any)
+        let exprs = self.expressions();
+        let expr = exprs
+            .iter()
+            .find(|expr| {
+                let range = span_to_range(&expr.1, rope).unwrap();
+                range.start <= position && position <= range.end
+            })
+            .or(exprs.last());
+        match expr {
+            Some(expr) => expr
+                .0
+                .get_completion_items_for_type(scope, position, rope, &Type::Any),
+            None => vec![],
+        }
     }
 }
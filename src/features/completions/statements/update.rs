@@ -2,12 +2,9 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{CompletionItem, Position};
 
 use crate::{
-    ast::statement::crud::update::UpdateStatement,
+    ast::statement::crud::{output::Output, update::UpdateStatement},
     declarations::{scoped_item::ScopedItems, type_::Type},
-    features::completions::{
-        has_completions::{HasCompletionItems, HasCompletionItemsForType},
-        table_name::get_completion_items_for_table_name,
-    },
+    features::completions::has_completions::{HasCompletionItems, HasCompletionItemsForType},
     util::range::span_to_range,
 };
 
@@ -19,25 +16,25 @@ impl HasCompletionItems for UpdateStatement {
         rope: &Rope,
     ) -> Vec<CompletionItem> {
         let mut scope = scope.clone();
-        if let Some(table) = &self.table {
-            let name_range = span_to_range(&table.1, rope).unwrap();
-            if name_range.start <= position && position <= name_range.end {
-                return get_completion_items_for_table_name(&scope);
+        if let Some(target) = &self.target {
+            let target_range = span_to_range(&target.1, rope).unwrap();
+            if target_range.start <= position && position <= target_range.end {
+                return target.0.get_completion_items(&scope, position, rope);
             }
-            let ty = match scope.table_definitions.get(&table.0) {
+            let ty = match target.0.get_definition(&scope) {
                 Some(obj) => {
                     for field in &obj.fields {
                         scope.scoped_table.fields.retain(|f| f.name != field.name);
                         scope.scoped_table.fields.push(field.clone());
                     }
-                    Type::Object(obj.clone())
+                    Type::Object(obj)
                 }
                 None => Type::Any,
             };
-            if let Some(content) = &self.content {
-                let content_range = span_to_range(&content.1, rope).unwrap();
-                if content_range.start <= position && position <= content_range.end {
-                    return content
+            if let Some(data) = &self.data {
+                let data_range = span_to_range(&data.1, rope).unwrap();
+                if data_range.start <= position && position <= data_range.end {
+                    return data
                         .0
                         .get_completion_items_for_type(&scope, position, rope, &ty);
                 }
@@ -49,6 +46,14 @@ impl HasCompletionItems for UpdateStatement {
                 return transform.0.get_completion_items(&scope, position, rope);
             }
         }
-        return vec![];
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                let projection_range = span_to_range(&projection.1, rope).unwrap();
+                if projection_range.start <= position && position <= projection_range.end {
+                    return projection.0.get_completion_items(&scope, position, rope);
+                }
+            }
+        }
+        vec![]
     }
 }
@@ -0,0 +1,63 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{CompletionItem, Position};
+
+use crate::{
+    ast::statement::crud::{output::Output, upsert::UpsertStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::completions::{
+        has_completions::{HasCompletionItems, HasCompletionItemsForType},
+        table_name::get_completion_items_for_table_name,
+    },
+    util::range::span_to_range,
+};
+
+impl HasCompletionItems for UpsertStatement {
+    fn get_completion_items(
+        &self,
+        scope: &ScopedItems,
+        position: Position,
+        rope: &Rope,
+    ) -> Vec<CompletionItem> {
+        let mut scope = scope.clone();
+        let Some(target) = &self.target else {
+            return get_completion_items_for_table_name(&scope);
+        };
+        let target_range = span_to_range(&target.1, rope).unwrap();
+        if target_range.start <= position && position <= target_range.end {
+            return target.0.get_completion_items(&scope, position, rope);
+        }
+        let ty = match target.0.get_definition(&scope) {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj)
+            }
+            None => Type::Any,
+        };
+        if let Some(data) = &self.data {
+            let data_range = span_to_range(&data.1, rope).unwrap();
+            if data_range.start <= position && position <= data_range.end {
+                return data
+                    .0
+                    .get_completion_items_for_type(&scope, position, rope, &ty);
+            }
+        }
+        for transform in &self.transforms {
+            let transform_range = span_to_range(&transform.1, rope).unwrap();
+            if transform_range.start <= position && position <= transform_range.end {
+                return transform.0.get_completion_items(&scope, position, rope);
+            }
+        }
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                let projection_range = span_to_range(&projection.1, rope).unwrap();
+                if projection_range.start <= position && position <= projection_range.end {
+                    return projection.0.get_completion_items(&scope, position, rope);
+                }
+            }
+        }
+        vec![]
+    }
+}
@@ -0,0 +1,64 @@
+use tower_lsp::lsp_types::{GotoDefinitionParams, GotoDefinitionResponse};
+
+use crate::ls::backend::Backend;
+
+use super::references::find::{get_declaration, get_reference_at};
+
+pub async fn get_definition(
+    backend: &Backend,
+    params: GotoDefinitionParams,
+) -> Option<GotoDefinitionResponse> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rope = backend.document_map.get(uri.as_str())?.value().clone();
+    let ast = backend.ast_map.get(uri.as_str())?.value().clone();
+    let scope = backend.state.lock().await.clone();
+    let (reference, _) = get_reference_at(&ast, &scope, position, &rope)?;
+    let location = get_declaration(&reference, &uri, &rope, &scope)?;
+    Some(GotoDefinitionResponse::Scalar(location))
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{Position, Range, Url};
+
+    use crate::{
+        declarations::scoped_item::ScopedItems,
+        features::references::find::{get_declaration, get_reference_at},
+        ls::workspace::{attach_locations, get_table_defs},
+        util::testing::{parse, SCHEMA},
+    };
+
+    #[test]
+    fn tables_fields_and_variables_lead_to_their_declarations() {
+        let src = format!("{SCHEMA}LET $p = 1;\nSELECT name FROM person WHERE age > $p;");
+        let uri = Url::parse("file:///schema.surql").unwrap();
+        let rope = Rope::from_str(&src);
+        let files = [(uri.clone(), rope.clone(), parse(&src))];
+        let mut table_definitions = get_table_defs(files.iter().map(|(_, _, file)| file.clone()));
+        attach_locations(&files, &mut table_definitions);
+        let scope = ScopedItems {
+            table_definitions,
+            ..Default::default()
+        };
+        let definition = |character| {
+            let (reference, _) =
+                get_reference_at(&files[0].2, &scope, Position::new(11, character), &rope)?;
+            let location = get_declaration(&reference, &uri, &rope, &scope)?;
+            assert_eq!(location.uri, uri);
+            Some(location.range)
+        };
+        let range = |line, start, end| {
+            Some(Range::new(
+                Position::new(line, start),
+                Position::new(line, end),
+            ))
+        };
+        assert_eq!(definition(8), range(2, 13, 17));
+        assert_eq!(definition(18), range(1, 13, 19));
+        assert_eq!(definition(31), range(3, 13, 16));
+        assert_eq!(definition(37), range(10, 4, 6));
+        assert_eq!(definition(26), None);
+    }
+}
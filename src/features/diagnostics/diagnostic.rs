@@ -2,8 +2,11 @@ use chumsky::{prelude::Input, Parser};
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 
 use crate::{
-    ast::parser::File, declarations::scoped_item::ScopedItems, lexer::lexer::lexer,
-    parser::parser::parser, util::range::span_to_range,
+    ast::parser::File,
+    declarations::scoped_item::ScopedItems,
+    lexer::lexer::{lexer, remove_comments},
+    parser::parser::parser,
+    util::range::span_to_range,
 };
 use ropey::Rope;
 
@@ -38,10 +41,11 @@ pub fn parse_file<'rope>(
         });
     }
     if let Some(tokens) = tokens {
+        let tokens = remove_comments(tokens);
         let parser_result = parser().parse_with_state(
             tokens
                 .as_slice()
-                .spanned((rope.len_chars()..rope.len_chars()).into()),
+                .spanned((rope.len_bytes()..rope.len_bytes()).into()),
             &mut scope,
         );
         let (ast, errs) = parser_result.into_output_errors();
@@ -0,0 +1,86 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{
+        expr::{parser::Expression, types::Typed},
+        type_::Type as AstType,
+    },
+    declarations::scoped_item::ScopedItems,
+    features::diagnostics::{
+        diagnostic::HasDiagnostic, statement::table_name::diagnostics_for_table_name,
+    },
+    ls::properties::parse_declared_type,
+    util::{range::span_to_range, span::Spanned},
+};
+
+pub fn get_cast_diagnostics(
+    ty: &Spanned<AstType>,
+    expr: &Spanned<Expression>,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = expr.diagnostics(rope, scope);
+    if ty.0.name.0 == "future" {
+        return diagnostics;
+    }
+    let unknown = diagnostics_for_type_name(ty, rope, scope);
+    if !unknown.is_empty() {
+        diagnostics.extend(unknown);
+        return diagnostics;
+    }
+    let from = expr.0.get_type(scope);
+    let to = parse_declared_type(&ty.0);
+    if !from.can_cast_to(&to) {
+        diagnostics.push(Diagnostic {
+            range: span_to_range(&expr.1, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::WARNING),
+            message: format!("Cannot cast {} to {}", from, to),
+            ..Default::default()
+        });
+    }
+    diagnostics
+}
+
+/// The types SurrealDB knows of besides `future`, which is only a cast.
+const TYPE_NAMES: [&str; 20] = [
+    "any", "array", "bool", "boolean", "bytes", "datetime", "decimal", "duration", "float",
+    "geometry", "int", "null", "number", "object", "option", "record", "regex", "set", "string",
+    "uuid",
+];
+
+/// Reports the type names in `ty` which aren't types, and the tables its
+/// records name which aren't defined.
+fn diagnostics_for_type_name(
+    ty: &Spanned<AstType>,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let name = &ty.0.name;
+    if !TYPE_NAMES.contains(&name.0.as_str()) {
+        return vec![Diagnostic {
+            message: format!("Type '{}' not found", name.0),
+            range: span_to_range(&name.1, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::ERROR),
+            ..Default::default()
+        }];
+    }
+    match name.0.as_str() {
+        "record" => {
+            ty.0.args
+                .iter()
+                .flat_map(|table| {
+                    diagnostics_for_table_name(&table.0.name.0, &table.0.name.1, rope, scope)
+                })
+                .collect()
+        }
+        // The arguments of an object type are its fields.
+        "object" => vec![],
+        _ => {
+            ty.0.args
+                .iter()
+                .flat_map(|arg| diagnostics_for_type_name(arg, rope, scope))
+                .collect()
+        }
+    }
+}
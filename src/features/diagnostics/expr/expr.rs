@@ -9,7 +9,10 @@ use crate::{
 };
 
 use super::{
-    function::get_function_call_diagnostics_for_type, op::get_bin_op_diagnostics_for_type,
+    cast::get_cast_diagnostics,
+    function::get_function_call_diagnostics_for_type,
+    graph::get_graph_diagnostics,
+    op::{get_bin_op_diagnostics, get_unary_op_diagnostics},
     variable::get_variable_diagnostics_for_type,
 };
 
@@ -31,6 +34,8 @@ impl HasDiagnosticsForType for Spanned<Expression> {
             let actual_type = &self.0.get_type(scope);
             match actual_type {
                 Type::Null => return self.diagnostics_for_type(rope, &Type::Null, scope),
+                // An optional value can be assigned as it is.
+                Type::Option(_) if type_.is_assignable_to(actual_type) => {}
                 _ => {
                     if !&inner.is_assignable_to(actual_type) {
                         diagnostics.push(Diagnostic {
@@ -103,20 +108,27 @@ impl HasDiagnosticsForType for Spanned<Expression> {
                 ));
             }
             (Expression::CodeBlock(block), s) => diagnostics.extend(block.diagnostics(rope, scope)),
-            (Expression::Binary { left, right, op }, s) => diagnostics.extend(
-                get_bin_op_diagnostics_for_type(left, right, op, rope, type_, scope),
-            ),
+            (Expression::Binary { left, right, op }, _) => {
+                diagnostics.extend(get_bin_op_diagnostics(left, right, op, rope, scope))
+            }
+            (Expression::Cast { ty, expr }, _) => {
+                diagnostics.extend(get_cast_diagnostics(ty, expr, rope, scope));
+            }
+            (Expression::Unary { op, expr }, _) => {
+                diagnostics.extend(get_unary_op_diagnostics(op, expr, rope, scope));
+            }
             (Expression::Access { expr, access }, _) => match &access.0.as_ref() {
                 Access::Property(name) => {
+                    diagnostics.extend(expr.diagnostics(rope, scope));
                     let mut ty = expr.0.get_type(scope);
                     let mut array_nest_count = 0;
-                    while let Type::Array(inner_ty) = ty {
+                    while let Type::Array(inner_ty) | Type::Option(inner_ty) = ty {
                         ty = *inner_ty.clone();
                         array_nest_count += 1;
                     }
-                    if let Type::Object(obj) = ty {
+                    if let Type::Object(obj) = scope.dereference(ty.clone()) {
                         let prop = obj.get_field(name);
-                        if prop.is_none() {
+                        if prop.is_none() && !obj.is_flexible {
                             diagnostics.push(Diagnostic {
                                 range: span_to_range(&self.1, rope).unwrap(),
                                 severity: Some(DiagnosticSeverity::ERROR),
@@ -125,7 +137,7 @@ impl HasDiagnosticsForType for Spanned<Expression> {
                                 ..Default::default()
                             });
                         }
-                    } else {
+                    } else if !matches!(ty, Type::Error) {
                         diagnostics.push(Diagnostic {
                             range: span_to_range(&expr.1, rope).unwrap(),
                             severity: Some(DiagnosticSeverity::ERROR),
@@ -151,7 +163,15 @@ impl HasDiagnosticsForType for Spanned<Expression> {
                         ));
                     }
                 }
+                Access::Graph(graph) => {
+                    diagnostics.extend(expr.diagnostics(rope, scope));
+                    diagnostics.extend(get_graph_diagnostics(graph, rope, scope));
+                }
+                Access::All => diagnostics.extend(expr.diagnostics(rope, scope)),
             },
+            (Expression::Graph(graph), _) => {
+                diagnostics.extend(get_graph_diagnostics(graph, rope, scope));
+            }
             (Expression::Literal(lit), s) => {
                 diagnostics.extend((lit, self.1.clone()).diagnostics_for_type(rope, type_, scope));
             }
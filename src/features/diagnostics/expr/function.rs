@@ -2,9 +2,13 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 
 use crate::{
-    ast::expr::parser::Expression,
-    declarations::{functions::GenericType, scoped_item::ScopedItems, type_::Type},
-    features::diagnostics::diagnostic::HasDiagnosticsForType,
+    ast::expr::{parser::Expression, types::Typed},
+    declarations::{
+        functions::{ArgKind, FunctionArg, GenericType, Signature},
+        scoped_item::ScopedItems,
+        type_::Type,
+    },
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
     util::{
         range::span_to_range,
         span::{Span, Spanned},
@@ -19,59 +23,142 @@ pub fn get_function_call_diagnostics_for_type(
     args: &Vec<Spanned<Expression>>,
 ) -> Vec<Diagnostic> {
     if let Some(found) = scope.functions.get(name) {
-        let mut diagnostics = vec![];
-        for (i, arg) in args.iter().enumerate() {
-            let function_arg = &found.args.get(i);
-            if let Some(function_arg) = function_arg {
-                match function_arg.1 {
-                    GenericType::Named(ref ty) => {
-                        diagnostics.extend(arg.diagnostics_for_type(rope, ty, scope));
-                    }
-                    _ => {}
-                }
-            } else {
+        if let [signature] = found.overloads.as_slice() {
+            return get_signature_diagnostics(rope, scope, span, name, signature, args);
+        }
+        let arg_types = args
+            .iter()
+            .map(|arg| scope.get_arg_type(name, arg.0.get_type(scope)))
+            .collect::<Vec<_>>();
+        if let Some(signature) = found.overloads.iter().find(|s| s.matches(&arg_types)) {
+            return get_signature_diagnostics(rope, scope, span, name, signature, args);
+        }
+        // Point at the argument which keeps the closest overload from
+        // matching, or the whole call if none takes as many arguments.
+        let span = match found.get_closest_overload(&arg_types) {
+            Some((_, index)) => &args[index].1,
+            None => span,
+        };
+        let mut diagnostics = vec![Diagnostic {
+            range: span_to_range(span, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: format!(
+                "No overload of function '{}' matches the arguments ({}). Candidates are:\n{}",
+                name,
+                arg_types
+                    .iter()
+                    .map(|ty| ty.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                found
+                    .overloads
+                    .iter()
+                    .map(|signature| format!("{}{}", name, signature))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+            ..Default::default()
+        }];
+        for arg in args {
+            diagnostics.extend(arg.diagnostics(rope, scope));
+        }
+        diagnostics
+    } else {
+        vec![Diagnostic {
+            range: span_to_range(span, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: format!("Function '{}' does not exist", name),
+            ..Default::default()
+        }]
+    }
+}
+
+fn get_signature_diagnostics(
+    rope: &Rope,
+    scope: &ScopedItems,
+    span: &Span,
+    name: &str,
+    signature: &Signature,
+    args: &[Spanned<Expression>],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut found = None;
+    let is_aggregate = scope.is_aggregate_call(name);
+    for (i, arg) in args.iter().enumerate() {
+        if let (true, Some(FunctionArg(_, ty, _))) = (is_aggregate, signature.get_arg(i)) {
+            // Each record of the group gives one item of the array.
+            let actual = arg.0.get_type(scope);
+            if !ty.unify(&scope.get_arg_type(name, actual.clone()), &mut found) {
+                let expected = match ty.bound() {
+                    Type::Array(inner) => *inner,
+                    bound => bound,
+                };
                 diagnostics.push(Diagnostic {
                     range: span_to_range(&arg.1, rope).unwrap(),
                     severity: Some(DiagnosticSeverity::ERROR),
-                    message: format!("Function '{}' does not have {} arguments", name, i + 1),
+                    message: format!("Expected type {}, found type {}", expected, actual),
                     ..Default::default()
                 });
             }
-        }
-        if found.args.len() > args.len() {
-            let missing = &found.args[args.len()..];
-            let message = if missing.len() == 1 {
-                format!(
-                    "Function '{}' is missing 1 argument: '{}'",
-                    name, missing[0].0
-                )
-            } else {
-                format!(
-                    "Function '{}' is missing {} arguments: {}",
-                    name,
-                    missing.len(),
-                    missing
-                        .iter()
-                        .map(|x| format!("'{}'", x.0))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )
-            };
-
+            diagnostics.extend(arg.diagnostics(rope, scope));
+        } else if let Some(FunctionArg(_, GenericType::Named(ty), _)) = signature.get_arg(i) {
+            // Values of unknown type, such as the results of `http::get`, fit
+            // any parameter.
+            match arg.0.get_type(scope) {
+                Type::Any => diagnostics.extend(arg.diagnostics(rope, scope)),
+                _ => diagnostics.extend(arg.diagnostics_for_type(rope, ty, scope)),
+            }
+        } else if let Some(FunctionArg(_, ty, _)) = signature.get_arg(i) {
+            let actual = arg.0.get_type(scope);
+            if !ty.unify(&actual, &mut found) {
+                diagnostics.push(Diagnostic {
+                    range: span_to_range(&arg.1, rope).unwrap(),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    message: format!("Expected type {}, found type {}", ty.bound(), actual),
+                    ..Default::default()
+                });
+            }
+            diagnostics.extend(arg.diagnostics(rope, scope));
+        } else {
             diagnostics.push(Diagnostic {
-                range: span_to_range(span, rope).unwrap(),
+                range: span_to_range(&arg.1, rope).unwrap(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                message,
+                message: format!("Function '{}' does not have {} arguments", name, i + 1),
                 ..Default::default()
             });
         }
-        diagnostics
-    } else {
-        vec![Diagnostic {
+    }
+    let missing = signature
+        .args
+        .iter()
+        .skip(args.len())
+        .filter(|arg| arg.2 == ArgKind::Required)
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        let message = if missing.len() == 1 {
+            format!(
+                "Function '{}' is missing 1 argument: '{}'",
+                name, missing[0].0
+            )
+        } else {
+            format!(
+                "Function '{}' is missing {} arguments: {}",
+                name,
+                missing.len(),
+                missing
+                    .iter()
+                    .map(|x| format!("'{}'", x.0))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+
+        diagnostics.push(Diagnostic {
             range: span_to_range(span, rope).unwrap(),
             severity: Some(DiagnosticSeverity::ERROR),
-            message: format!("Function '{}' does not exist", name),
+            message,
             ..Default::default()
-        }]
+        });
     }
+    diagnostics
 }
@@ -0,0 +1,22 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::Diagnostic;
+
+use crate::{
+    ast::expr::access::Graph,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::{
+        diagnostic::HasDiagnosticsForType, statement::table_name::diagnostics_for_table_name,
+    },
+};
+
+pub fn get_graph_diagnostics(graph: &Graph, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+    let mut diagnostics = diagnostics_for_table_name(&graph.table.0, &graph.table.1, rope, scope);
+    if let Some(condition) = &graph.condition {
+        diagnostics.extend(condition.diagnostics_for_type(
+            rope,
+            &Type::Bool,
+            &graph.get_condition_scope(scope),
+        ));
+    }
+    diagnostics
+}
@@ -1,6 +1,8 @@
 pub mod array;
+pub mod cast;
 pub mod expr;
 pub mod function;
+pub mod graph;
 pub mod ident;
 pub mod literal;
 pub mod object;
@@ -49,6 +49,10 @@ impl HasDiagnosticsForType for Spanned<&Vec<Spanned<ObjectEntry>>> {
                                 ));
                             }
                         }
+                    } else if obj.is_flexible {
+                        if let Some(value) = value {
+                            diagnostics.extend(value.diagnostics_for_type(rope, &Type::Any, scope));
+                        }
                     } else {
                         diagnostics.push(Diagnostic {
                             range: span_to_range(&key.1, rope).unwrap(),
@@ -76,9 +80,12 @@ impl HasDiagnosticsForType for Spanned<&Vec<Spanned<ObjectEntry>>> {
                 }
                 diagnostics
             }
-            _ => {
-                vec![]
-            }
+            _ => self
+                .0
+                .iter()
+                .filter_map(|entry| entry.0.value.as_ref())
+                .flat_map(|value| value.diagnostics_for_type(rope, &Type::Any, scope))
+                .collect(),
         }
     }
 }
@@ -2,18 +2,19 @@ use ropey::Rope;
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Range};
 
 use crate::{
-    ast::expr::{op::BinaryOperator, parser::Expression, types::Typed},
+    ast::expr::{op::BinaryOperator, parser::Expression, types::Typed, unary::UnaryOperator},
     declarations::{scoped_item::ScopedItems, type_::Type},
-    features::diagnostics::diagnostic::HasDiagnosticsForType,
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
     util::{range::span_to_range, span::Spanned},
 };
 
-pub fn get_bin_op_diagnostics_for_type(
+/// Checks the operands of a binary operation. Its result is checked against
+/// the expected type along with every other expression.
+pub fn get_bin_op_diagnostics(
     left: &Spanned<Expression>,
     right: &Spanned<Expression>,
     op: &Spanned<BinaryOperator>,
     rope: &Rope,
-    type_: &Type,
     scope: &ScopedItems,
 ) -> Vec<Diagnostic> {
     let mut diagnostics = vec![];
@@ -23,25 +24,25 @@ pub fn get_bin_op_diagnostics_for_type(
     let right_type = right.0.get_type(scope);
     let left_range = span_to_range(&left.1, rope).unwrap();
     let right_range = span_to_range(&right.1, rope).unwrap();
+    let full_range = Range {
+        start: left_range.start,
+        end: right_range.end,
+    };
     match &op.0 {
         BinaryOperator::Add
         | BinaryOperator::Subtract
         | BinaryOperator::Multiply
         | BinaryOperator::Divide
-        | BinaryOperator::Modulo => {
-            if !left_type.is_numeric() && left_type != Type::Error {
-                diagnostics.push(Diagnostic {
-                    range: left_range,
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    message: format!("Expected numeric type, found {}", left_type.to_string()),
-                    ..Default::default()
-                });
-            }
-            if !right_type.is_numeric() && right_type != Type::Error {
+        | BinaryOperator::Modulo
+        | BinaryOperator::Power => {
+            if left_type != Type::Error
+                && right_type != Type::Error
+                && op.0.get_result_type(&left_type, &right_type) == Type::Error
+            {
                 diagnostics.push(Diagnostic {
-                    range: right_range,
+                    range: full_range,
                     severity: Some(DiagnosticSeverity::ERROR),
-                    message: format!("Expected numeric type, found {}", right_type.to_string()),
+                    message: format!("Cannot apply {} to {} and {}", op.0, left_type, right_type),
                     ..Default::default()
                 });
             }
@@ -50,38 +51,161 @@ pub fn get_bin_op_diagnostics_for_type(
         | BinaryOperator::NotEquals
         | BinaryOperator::LessThan
         | BinaryOperator::LessThanOrEqual
-        | BinaryOperator::GreaterThan => {
-            if left_type == Type::Error || right_type == Type::Error {
-                return diagnostics;
-            }
-
-            if !left_type.is_assignable_to(&right_type) && !left_type.is_assignable_to(&right_type)
-            {
+        | BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterThanOrEqual => {
+            if !left_type.is_comparable_to(&right_type) {
                 diagnostics.push(Diagnostic {
-                    range: Range {
-                        start: left_range.start,
-                        end: right_range.end,
-                    },
+                    range: full_range,
                     severity: Some(DiagnosticSeverity::ERROR),
-                    message: format!(
-                        "Cannot compare {} and {}",
-                        left_type.to_string(),
-                        right_type.to_string()
-                    ),
+                    message: format!("Cannot compare {} and {}", left_type, right_type),
                     ..Default::default()
                 });
             }
         }
-        _ => {}
+        BinaryOperator::Contains | BinaryOperator::ContainsNot => {
+            diagnostics.extend(get_membership_diagnostics(
+                (&left_type, left_range),
+                &right_type,
+                full_range,
+            ));
+        }
+        BinaryOperator::Inside | BinaryOperator::NotInside => {
+            diagnostics.extend(get_membership_diagnostics(
+                (&right_type, right_range),
+                &left_type,
+                full_range,
+            ));
+        }
+        BinaryOperator::ContainsAll
+        | BinaryOperator::ContainsAny
+        | BinaryOperator::ContainsNone
+        | BinaryOperator::AllInside
+        | BinaryOperator::AnyInside
+        | BinaryOperator::NoneInside => {
+            diagnostics.extend(check_operand(
+                &left_type,
+                left_range,
+                "array",
+                Type::is_array,
+            ));
+            diagnostics.extend(check_operand(
+                &right_type,
+                right_range,
+                "array",
+                Type::is_array,
+            ));
+            if let (Type::Array(left_item), Type::Array(right_item)) =
+                (left_type.unwrap_option(), right_type.unwrap_option())
+            {
+                if !left_item.is_comparable_to(right_item) {
+                    diagnostics.push(Diagnostic {
+                        range: full_range,
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        message: format!("Cannot compare {} and {}", left_item, right_item),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+        BinaryOperator::Like | BinaryOperator::NotLike | BinaryOperator::Matches(_) => {
+            diagnostics.extend(check_operand(
+                &left_type,
+                left_range,
+                "string",
+                Type::is_string,
+            ));
+            diagnostics.extend(check_operand(
+                &right_type,
+                right_range,
+                "string",
+                Type::is_string,
+            ));
+        }
+        BinaryOperator::Knn(_) => {
+            diagnostics.extend(check_operand(
+                &left_type,
+                left_range,
+                "vector",
+                Type::is_vector,
+            ));
+            diagnostics.extend(check_operand(
+                &right_type,
+                right_range,
+                "vector",
+                Type::is_vector,
+            ));
+        }
+        BinaryOperator::And
+        | BinaryOperator::Or
+        | BinaryOperator::NullCoalesce
+        | BinaryOperator::Truthy
+        | BinaryOperator::Outside
+        | BinaryOperator::Intersects => {}
     }
     diagnostics
 }
 
-impl Type {
-    fn is_numeric(&self) -> bool {
-        match self {
-            Type::Int | Type::Float => true,
-            _ => false,
+pub fn get_unary_op_diagnostics(
+    op: &Spanned<UnaryOperator>,
+    expr: &Spanned<Expression>,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = expr.diagnostics(rope, scope);
+    let ty = expr.0.get_type(scope);
+    if ty != Type::Error && op.0.get_result_type(&ty) == Type::Error {
+        diagnostics.push(Diagnostic {
+            range: span_to_range(&expr.1, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: format!("Cannot apply {} to {}", op.0, ty),
+            ..Default::default()
+        });
+    }
+    diagnostics
+}
+
+/// Reports an operand of type `ty` which `is_valid` rejects.
+fn check_operand(
+    ty: &Type,
+    range: Range,
+    expected: &str,
+    is_valid: impl Fn(&Type) -> bool,
+) -> Option<Diagnostic> {
+    match ty.unwrap_option() {
+        Type::Any | Type::Error => None,
+        inner if is_valid(inner) => None,
+        _ => Some(Diagnostic {
+            range,
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: format!("Expected {}, found {}", expected, ty),
+            ..Default::default()
+        }),
+    }
+}
+
+/// Checks that `item` can be looked for in `container`, which is either an
+/// array of comparable items or a string to search for a substring.
+fn get_membership_diagnostics(
+    (container, container_range): (&Type, Range),
+    item: &Type,
+    full_range: Range,
+) -> Vec<Diagnostic> {
+    let item_type = match container.unwrap_option() {
+        Type::Array(inner) => inner.as_ref(),
+        Type::String => &Type::String,
+        _ => {
+            return check_operand(container, container_range, "array or string", |_| false)
+                .into_iter()
+                .collect();
         }
+    };
+    if item_type.is_comparable_to(item) {
+        return vec![];
     }
+    vec![Diagnostic {
+        range: full_range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        message: format!("Cannot look for {} in {}", item, container),
+        ..Default::default()
+    }]
 }
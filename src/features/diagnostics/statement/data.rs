@@ -0,0 +1,225 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{
+        expr::{literal::Literal, parser::Expression, types::Typed},
+        statement::crud::data::{Assignment, AssignmentOperator, Data},
+    },
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
+    util::{range::span_to_range, span::Spanned},
+};
+
+const PATCH_OPERATIONS: [&str; 6] = ["add", "remove", "replace", "copy", "move", "test"];
+
+impl HasDiagnosticsForType for Spanned<Data> {
+    fn diagnostics_for_type(
+        &self,
+        rope: &Rope,
+        type_: &Type,
+        scope: &ScopedItems,
+    ) -> Vec<Diagnostic> {
+        match &self.0 {
+            Data::Content(Some(content)) | Data::Replace(Some(content)) => {
+                content.diagnostics_for_type(rope, type_, scope)
+            }
+            Data::Merge(Some(content)) => {
+                content.diagnostics_for_type(rope, &get_partial_type(type_), scope)
+            }
+            Data::Patch(Some(patch)) => get_patch_diagnostics(patch, rope, scope),
+            Data::Content(None) | Data::Replace(None) | Data::Merge(None) | Data::Patch(None) => {
+                vec![]
+            }
+            Data::Set(assignments) => get_assignment_diagnostics(assignments, rope, type_, scope),
+            Data::Unset(fields) => {
+                let mut diagnostics = vec![];
+                for path in fields {
+                    let mut ty = Some(type_.clone());
+                    let mut field = None;
+                    for (name, span) in path {
+                        field = match &ty {
+                            Some(Type::Any) | None => None,
+                            Some(parent) => {
+                                let field = parent.get_field(name).cloned();
+                                if field.is_none() {
+                                    diagnostics.push(Diagnostic {
+                                        range: span_to_range(span, rope).unwrap(),
+                                        severity: Some(DiagnosticSeverity::ERROR),
+                                        message: format!("Field {} does not exist", name),
+                                        ..Default::default()
+                                    });
+                                }
+                                field
+                            }
+                        };
+                        ty = field.as_ref().map(|field| field.ty.clone());
+                    }
+                    if let (Some(field), Some((_, span))) = (field, path.last()) {
+                        if field.is_required {
+                            diagnostics.push(Diagnostic {
+                                range: span_to_range(span, rope).unwrap(),
+                                severity: Some(DiagnosticSeverity::ERROR),
+                                message: format!("Cannot unset required field {}", field.name),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+                diagnostics
+            }
+        }
+    }
+}
+
+/// Checks the field paths of `assignments` against `type_`, and each value
+/// against the type of the field it is assigned to.
+pub fn get_assignment_diagnostics(
+    assignments: &[Spanned<Assignment>],
+    rope: &Rope,
+    type_: &Type,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for (assignment, _) in assignments {
+        let mut ty = Some(type_.clone());
+        for (name, span) in &assignment.field {
+            ty = match ty {
+                Some(Type::Any) | None => None,
+                Some(parent) => match parent.get_field(name) {
+                    Some(field) => Some(field.ty.clone()),
+                    None => {
+                        diagnostics.push(Diagnostic {
+                            range: span_to_range(span, rope).unwrap(),
+                            severity: Some(DiagnosticSeverity::ERROR),
+                            message: format!("Field {} does not exist", name),
+                            ..Default::default()
+                        });
+                        None
+                    }
+                },
+            };
+        }
+        let ty = match (ty, &assignment.op) {
+            (Some(ty), AssignmentOperator::Assign) => Some(ty),
+            (Some(ty), op) => {
+                let operand = get_operand_type(&ty, &assignment.value.0.get_type(scope), op);
+                if operand.is_none() {
+                    let start = assignment.field.first().map(|field| field.1.start);
+                    let end = assignment.field.last().map(|field| field.1.end);
+                    if let (Some(start), Some(end)) = (start, end) {
+                        diagnostics.push(Diagnostic {
+                            range: span_to_range(&(start..end).into(), rope).unwrap(),
+                            severity: Some(DiagnosticSeverity::ERROR),
+                            message: format!("Cannot use {} on a field of type {}", op, ty),
+                            ..Default::default()
+                        });
+                    }
+                }
+                operand
+            }
+            (None, _) => None,
+        };
+        match ty {
+            Some(ty) => {
+                diagnostics.extend(assignment.value.diagnostics_for_type(rope, &ty, scope));
+            }
+            None => diagnostics.extend(assignment.value.diagnostics(rope, scope)),
+        }
+    }
+    diagnostics
+}
+
+/// The type of the value that `op` adds to or removes from a field of type
+/// `ty`, given that the value is of type `value`, or `None` if the field
+/// can't be modified this way.
+fn get_operand_type(ty: &Type, value: &Type, op: &AssignmentOperator) -> Option<Type> {
+    match ty {
+        Type::Option(inner) => get_operand_type(inner, value, op),
+        // Arrays take single items as well as other arrays.
+        Type::Array(inner) => match value {
+            Type::Array(_) => Some(ty.clone()),
+            _ => Some(*inner.clone()),
+        },
+        Type::Int | Type::Float | Type::Decimal | Type::Number | Type::Duration => Some(ty.clone()),
+        Type::String if *op == AssignmentOperator::Add => Some(Type::String),
+        Type::DateTime => Some(Type::Duration),
+        Type::Any | Type::Error => Some(Type::Any),
+        _ => None,
+    }
+}
+
+/// `ty` with every field made optional, as `MERGE` only writes the fields it
+/// is given.
+fn get_partial_type(ty: &Type) -> Type {
+    match ty {
+        Type::Object(obj) => Type::Object(Object {
+            fields: obj
+                .fields
+                .iter()
+                .map(|field| Field {
+                    ty: get_partial_type(&field.ty),
+                    is_required: false,
+                    ..field.clone()
+                })
+                .collect(),
+            location: obj.location.clone(),
+            is_flexible: false,
+        }),
+        Type::Option(inner) => Type::Option(Box::new(get_partial_type(inner))),
+        _ => ty.clone(),
+    }
+}
+
+/// Checks a `PATCH` clause, which takes an array of JSON Patch operations.
+fn get_patch_diagnostics(
+    patch: &Spanned<Expression>,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let field = |name: &str, ty: Type, is_required: bool| Field {
+        name: name.to_string(),
+        ty,
+        is_required,
+        location: None,
+        owner: None,
+    };
+    let operation = Type::Object(Object {
+        fields: vec![
+            field("op", Type::String, true),
+            field("path", Type::String, true),
+            field("from", Type::String, false),
+            field("value", Type::Any, false),
+        ],
+        location: None,
+        is_flexible: false,
+    });
+    let mut diagnostics =
+        patch.diagnostics_for_type(rope, &Type::Array(Box::new(operation)), scope);
+    if let Expression::Array(items) = &patch.0 {
+        for (item, _) in items {
+            let Expression::Object(entries) = item else {
+                continue;
+            };
+            for (entry, _) in entries {
+                if let ("op", Some((Expression::Literal(Literal::String(op)), span))) =
+                    (entry.key.0.as_str(), &entry.value)
+                {
+                    if !PATCH_OPERATIONS.contains(&op.as_str()) {
+                        diagnostics.push(Diagnostic {
+                            range: span_to_range(span, rope).unwrap(),
+                            severity: Some(DiagnosticSeverity::ERROR),
+                            message: format!(
+                                "Unknown patch operation '{}', expected one of: {}",
+                                op,
+                                PATCH_OPERATIONS.join(", ")
+                            ),
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+        }
+    }
+    diagnostics
+}
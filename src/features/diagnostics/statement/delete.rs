@@ -11,27 +11,19 @@ use crate::{
     util::span::Spanned,
 };
 
-use super::table_name::diagnostics_for_table_name;
-
 impl HasDiagnostic for Spanned<&DeleteStatement> {
     fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
         let mut diagnostics = vec![];
         let mut scope = scope.clone();
-        match &self.0.table {
-            Some(table) => {
-                diagnostics.extend(diagnostics_for_table_name(&table.0, &table.1, rope, &scope));
-                if let Some(obj) = scope.table_definitions.get(&table.0) {
-                    for field in &obj.fields {
-                        scope.scoped_table.fields.retain(|f| f.name != field.name);
-                        scope.scoped_table.fields.push(field.clone());
-                    }
-                    Type::Object(obj.clone())
-                } else {
-                    Type::Any
+        if let Some(target) = &self.0.target {
+            diagnostics.extend(target.0.diagnostics(rope, &scope));
+            if let Some(obj) = target.0.get_definition(&scope) {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
                 }
             }
-            None => Type::Any,
-        };
+        }
         for transform in &self.0.transforms {
             diagnostics.extend(transform.0.diagnostics(rope, &scope));
         }
@@ -41,16 +33,13 @@ impl HasDiagnostic for Spanned<&DeleteStatement> {
 
 impl Typed for DeleteStatement {
     fn get_type(&self, scope: &ScopedItems) -> Type {
-        let mut scope = scope.clone();
-        if let Some(table) = &self.table {
-            if let Some(obj) = scope.table_definitions.get(&table.0) {
-                for field in &obj.fields {
-                    scope.scoped_table.fields.retain(|f| f.name != field.name);
-                    scope.scoped_table.fields.push(field.clone());
-                }
-                return Type::Array(Box::new(Type::Object(obj.clone())));
-            }
+        let definition = self
+            .target
+            .as_ref()
+            .and_then(|target| target.0.get_definition(scope));
+        match definition {
+            Some(obj) => Type::Array(Box::new(Type::Object(obj))),
+            None => Type::Any,
         }
-        Type::Any
     }
 }
@@ -0,0 +1,20 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::Diagnostic;
+
+use crate::{
+    ast::statement::for_::ForStatement,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
+    util::span::Spanned,
+};
+
+impl HasDiagnostic for Spanned<&ForStatement> {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics =
+            self.0
+                .iterable
+                .diagnostics_for_type(rope, &Type::Array(Box::new(Type::Any)), scope);
+        diagnostics.extend(self.0.body.diagnostics(rope, &self.0.get_body_scope(scope)));
+        diagnostics
+    }
+}
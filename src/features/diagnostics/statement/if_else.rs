@@ -0,0 +1,50 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{expr::types::Typed, statement::if_else::IfElseStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::HasDiagnostic,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl HasDiagnostic for Spanned<&IfElseStatement> {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        for (condition, body) in &self.0.branches {
+            diagnostics.extend(condition.diagnostics(rope, scope));
+            // Any value is truthy or falsy, so records, strings and the like
+            // are tested for being set. Only a condition which is always
+            // `NONE` or `NULL` is a mistake.
+            if condition.0.get_type(scope) == Type::Null {
+                diagnostics.push(Diagnostic {
+                    range: span_to_range(&condition.1, rope).unwrap(),
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    message: "Condition is always falsy".to_string(),
+                    ..Default::default()
+                });
+            }
+            diagnostics.extend(body.diagnostics(rope, scope));
+        }
+        if let Some(otherwise) = &self.0.otherwise {
+            diagnostics.extend(otherwise.diagnostics(rope, scope));
+        }
+        diagnostics
+    }
+}
+
+impl Typed for IfElseStatement {
+    fn get_type(&self, scope: &ScopedItems) -> Type {
+        let ty = self
+            .bodies()
+            .into_iter()
+            .map(|body| body.0.get_type(scope))
+            .reduce(|a, b| a.get_shared_super_type(&b))
+            .unwrap_or(Type::Null);
+        match self.otherwise {
+            Some(_) => ty,
+            // Nothing is returned when no condition holds.
+            None => ty.get_shared_super_type(&Type::Null),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{
+        expr::{parser::Expression, types::Typed},
+        statement::crud::insert::{InsertStatement, InsertValues},
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
+    util::{
+        range::span_to_range,
+        span::{Span, Spanned},
+    },
+};
+
+use super::{data::get_assignment_diagnostics, table_name::diagnostics_for_table_name};
+
+impl HasDiagnostic for Spanned<&InsertStatement> {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut scope = scope.clone();
+        let ty = match &self.0.table {
+            Some(table) => {
+                diagnostics.extend(diagnostics_for_table_name(&table.0, &table.1, rope, &scope));
+                match scope.table_definitions.get(&table.0) {
+                    Some(obj) => {
+                        for field in &obj.fields {
+                            scope.scoped_table.fields.retain(|f| f.name != field.name);
+                            scope.scoped_table.fields.push(field.clone());
+                        }
+                        Type::Object(obj.clone())
+                    }
+                    None => Type::Any,
+                }
+            }
+            None => Type::Any,
+        };
+        match &self.0.values {
+            Some((InsertValues::Content(content), _)) => {
+                // Bulk inserts take an array of records.
+                let ty = match content.0.get_type(&scope) {
+                    Type::Array(_) => Type::Array(Box::new(ty.clone())),
+                    _ => ty.clone(),
+                };
+                diagnostics.extend(content.diagnostics_for_type(rope, &ty, &scope));
+            }
+            Some((InsertValues::Values { fields, rows }, span)) => {
+                diagnostics.extend(get_values_diagnostics(
+                    fields, rows, span, &ty, rope, &scope,
+                ));
+            }
+            None => {}
+        }
+        if let Some(assignments) = &self.0.on_duplicate {
+            let mut scope = scope.clone();
+            scope.variables.insert("input".to_string(), ty.clone());
+            diagnostics.extend(get_assignment_diagnostics(assignments, rope, &ty, &scope));
+        }
+        if let Some(output) = &self.0.output {
+            diagnostics.extend(output.0.diagnostics(rope, &scope));
+        }
+        diagnostics
+    }
+}
+
+/// Checks the rows of `(field, ...) VALUES (value, ...), ...` against the
+/// record type `type_`.
+fn get_values_diagnostics(
+    fields: &[Spanned<String>],
+    rows: &[Spanned<Vec<Spanned<Expression>>>],
+    span: &Span,
+    type_: &Type,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut types = vec![];
+    for (name, span) in fields {
+        types.push(match type_.get_field(name) {
+            Some(field) => field.ty.clone(),
+            None => {
+                if let Type::Object(_) = type_ {
+                    diagnostics.push(Diagnostic {
+                        range: span_to_range(span, rope).unwrap(),
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        message: format!("Field {} does not exist", name),
+                        ..Default::default()
+                    });
+                }
+                Type::Any
+            }
+        });
+    }
+    if let Type::Object(obj) = type_ {
+        let missing = obj
+            .fields
+            .iter()
+            .filter(|field| {
+                field.is_required && !fields.iter().any(|(name, _)| name == &field.name)
+            })
+            .map(|field| field.name.clone())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            diagnostics.push(Diagnostic {
+                range: span_to_range(span, rope).unwrap(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                message: format!("Missing fields: {}", missing.join(", ")),
+                ..Default::default()
+            });
+        }
+    }
+    for (row, row_span) in rows {
+        if row.len() != fields.len() {
+            diagnostics.push(Diagnostic {
+                range: span_to_range(row_span, rope).unwrap(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                message: format!("Expected {} values, found {}", fields.len(), row.len()),
+                ..Default::default()
+            });
+        }
+        for (index, value) in row.iter().enumerate() {
+            match types.get(index) {
+                Some(ty) => diagnostics.extend(value.diagnostics_for_type(rope, ty, scope)),
+                None => diagnostics.extend(value.diagnostics(rope, scope)),
+            }
+        }
+    }
+    diagnostics
+}
+
+impl Typed for InsertStatement {
+    fn get_type(&self, scope: &ScopedItems) -> Type {
+        let table = self
+            .table
+            .as_ref()
+            .and_then(|table| scope.table_definitions.get(&table.0));
+        let item = match (&self.output, table) {
+            (Some(output), _) => output.0.get_item_type(table, scope),
+            (None, Some(obj)) => Type::Object(obj.clone()),
+            (None, None) => return Type::Any,
+        };
+        Type::Array(Box::new(item))
+    }
+}
@@ -0,0 +1,71 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::Diagnostic;
+
+use crate::{
+    ast::statement::crud::output::Output,
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::HasDiagnostic,
+};
+
+use super::select::get_projected_fields;
+
+impl HasDiagnostic for Output {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        match self {
+            Output::Fields(projections) => projections
+                .iter()
+                .flat_map(|projection| projection.0.expr.diagnostics(rope, scope))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl Output {
+    /// The type of each record returned for the records written to `table`.
+    pub fn get_item_type(&self, table: Option<&Object>, scope: &ScopedItems) -> Type {
+        let record = match table {
+            Some(table) => Type::Object(table.clone()),
+            None => Type::Any,
+        };
+        match self {
+            Output::None => Type::Null,
+            Output::After => record,
+            // A new record has nothing before it was written.
+            Output::Before => record.get_shared_super_type(&Type::Null),
+            Output::Diff => Type::Array(Box::new(get_patch_type())),
+            Output::Fields(projections) => {
+                let mut scope = scope.clone();
+                for field in table.iter().flat_map(|table| &table.fields) {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(Object {
+                    fields: get_projected_fields(projections, table, &mut scope),
+                    location: None,
+                    is_flexible: false,
+                })
+            }
+        }
+    }
+}
+
+/// A JSON Patch operation, which `RETURN DIFF` returns the changes as.
+fn get_patch_type() -> Type {
+    let field = |name: &str, ty: Type| Field {
+        name: name.to_string(),
+        is_required: !matches!(ty, Type::Option(_)),
+        ty,
+        location: None,
+        owner: None,
+    };
+    Type::Object(Object {
+        fields: vec![
+            field("op", Type::String),
+            field("path", Type::String),
+            field("value", Type::Option(Box::new(Type::Any))),
+        ],
+        location: None,
+        is_flexible: false,
+    })
+}
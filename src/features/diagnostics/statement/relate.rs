@@ -0,0 +1,126 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{expr::types::Typed, statement::crud::relate::RelateStatement, target::Target},
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
+    util::{range::span_to_range, span::Spanned},
+};
+
+use super::table_name::diagnostics_for_table_name;
+
+impl HasDiagnostic for Spanned<&RelateStatement> {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut scope = scope.clone();
+        if let Some(edge) = &self.0.edge {
+            diagnostics.extend(diagnostics_for_table_name(&edge.0, &edge.1, rope, &scope));
+        }
+        let edge = self.0.get_edge_definition(&scope);
+        if let Some(from) = &self.0.from {
+            diagnostics.extend(diagnostics_for_node(from, "in", &edge, rope, &scope));
+        }
+        if let Some(to) = &self.0.to {
+            diagnostics.extend(diagnostics_for_node(to, "out", &edge, rope, &scope));
+        }
+        let ty = match &edge {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj.clone())
+            }
+            None => Type::Any,
+        };
+        if let Some(data) = &self.0.data {
+            diagnostics.extend(data.diagnostics_for_type(rope, &ty, &scope));
+        }
+        if let Some(output) = &self.0.output {
+            diagnostics.extend(output.0.diagnostics(rope, &scope));
+        }
+        diagnostics
+    }
+}
+
+/// Checks the records on one side of the edge against the edge table's
+/// definition of `field`, which is either `in` or `out`.
+fn diagnostics_for_node(
+    node: &Spanned<Box<Target>>,
+    field: &str,
+    edge: &Option<Object>,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Vec<Diagnostic> {
+    let found = node.0.get_type(scope);
+    let actual = get_node_type(found.clone());
+    // Either side can also be an array of records.
+    let expected = edge
+        .as_ref()
+        .and_then(|obj| obj.get_field(field))
+        .map(|field| match actual {
+            Type::Array(_) => Type::Array(Box::new(field.ty.clone())),
+            _ => field.ty.clone(),
+        });
+    let mut diagnostics = match (node.0.as_ref(), &expected) {
+        (Target::Expression(expr), Some(ty)) if actual == found => {
+            return expr.diagnostics_for_type(rope, ty, scope);
+        }
+        (Target::Expression(expr), _) => expr.diagnostics(rope, scope),
+        (target, _) => target.diagnostics(rope, scope),
+    };
+    let message = match expected {
+        Some(ty) if !ty.is_assignable_to(&actual) => {
+            format!("Expected type {}, found type {}", ty, actual)
+        }
+        None if !is_record(&actual) => format!("Expected a record, found type {}", actual),
+        _ => return diagnostics,
+    };
+    diagnostics.push(Diagnostic {
+        range: span_to_range(&node.1, rope).unwrap(),
+        severity: Some(DiagnosticSeverity::ERROR),
+        message,
+        ..Default::default()
+    });
+    diagnostics
+}
+
+/// Records written by `CREATE` and the like are returned as objects, which
+/// stand for the record their `id` is.
+fn get_node_type(ty: Type) -> Type {
+    match ty {
+        Type::Array(inner) => Type::Array(Box::new(get_node_type(*inner))),
+        Type::Object(obj) => match obj.get_field("id") {
+            Some(Field {
+                ty: Type::Record(table),
+                ..
+            }) => Type::Record(table.clone()),
+            _ => Type::Object(obj),
+        },
+        ty => ty,
+    }
+}
+
+fn is_record(ty: &Type) -> bool {
+    match ty {
+        Type::Record(_) | Type::Any | Type::Error => true,
+        Type::Array(inner) => is_record(inner),
+        _ => false,
+    }
+}
+
+impl Typed for RelateStatement {
+    fn get_type(&self, scope: &ScopedItems) -> Type {
+        let edge = self
+            .edge
+            .as_ref()
+            .and_then(|edge| scope.table_definitions.get(&edge.0));
+        let item = match (&self.output, edge) {
+            (Some(output), _) => output.0.get_item_type(edge, scope),
+            (None, Some(obj)) => Type::Object(obj.clone()),
+            (None, None) => return Type::Any,
+        };
+        Type::Array(Box::new(item))
+    }
+}
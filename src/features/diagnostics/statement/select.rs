@@ -1,52 +1,85 @@
 use ropey::Rope;
-use tower_lsp::lsp_types::Diagnostic;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 
 use crate::{
     ast::{
-        expr::{parser::Expression, types::Typed},
-        statement::crud::{select::SelectStatement, update::UpdateStatement},
+        expr::{
+            access::{Access, Graph},
+            parser::Expression,
+            types::Typed,
+        },
+        projection::Projection,
+        statement::{crud::select::SelectStatement, transform::Transform},
+        target::Target,
+    },
+    declarations::{
+        field::Field, functions::AGGREGATE_FUNCTIONS, object::Object, scoped_item::ScopedItems,
+        type_::Type,
     },
-    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
     features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
-    util::span::Spanned,
+    util::{
+        range::span_to_range,
+        span::{Span, Spanned},
+    },
 };
 
-use super::table_name::diagnostics_for_table_name;
-
 impl HasDiagnostic for Spanned<&SelectStatement> {
     fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
         let mut diagnostics = vec![];
         let mut scope = scope.clone();
-        if let Some(from) = &self.0.from {
-            diagnostics.extend(diagnostics_for_table_name(&from.0, &from.1, rope, &scope));
-            if let Some(ty) = scope.table_definitions.get(&from.0) {
+        for from in &self.0.from {
+            diagnostics.extend(from.0.diagnostics(rope, &scope));
+            if let Some(ty) = from.0.get_definition(&scope) {
                 for field in &ty.fields {
                     scope.scoped_table.fields.retain(|f| f.name != field.name);
                     scope.scoped_table.fields.push(field.clone());
                 }
             }
-            for projection in &self.0.projections {
-                let expr = &projection.0.expr;
-                diagnostics.extend(expr.diagnostics_for_type(rope, &Type::Any, &scope));
-                if let Some(alias) = &projection.0.alias {
-                    scope.scoped_table.fields.retain(|f| f.name != alias.0);
-                    scope.scoped_table.fields.push(Field {
-                        name: alias.0.clone(),
-                        ty: expr.0.get_type(&scope),
-                        is_required: match expr.0.get_type(&scope) {
-                            Type::Option(_) => false,
-                            _ => true,
-                        },
+        }
+        if self.0.only {
+            // A table, or several targets, can hold any number of records.
+            let many = match self.0.from.as_slice() {
+                [(Target::Table(_) | Target::Range(..), _)] => true,
+                [_] => false,
+                _ => true,
+            };
+            if many {
+                if let (Some(first), Some(last)) = (self.0.from.first(), self.0.from.last()) {
+                    diagnostics.push(Diagnostic {
+                        range: span_to_range(&(first.1.start..last.1.end).into(), rope).unwrap(),
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        message: "ONLY expects a single record".to_string(),
+                        ..Default::default()
                     });
                 }
             }
-            for transform in &self.0.transforms {
-                diagnostics.extend(transform.0.diagnostics(rope, &scope));
-            }
         }
+        let group = self.0.group();
+        scope.is_grouped = group.is_some();
         for projection in &self.0.projections {
             let expr = &projection.0.expr;
             diagnostics.extend(expr.diagnostics_for_type(rope, &Type::Any, &scope));
+            if let Some(group) = group {
+                diagnostics.extend(get_group_diagnostics(projection, group, rope));
+            }
+            if let Some(alias) = &projection.0.alias {
+                let ty = expr.0.get_type(&scope);
+                scope.scoped_table.fields.retain(|f| f.name != alias.0);
+                scope.scoped_table.fields.push(Field {
+                    name: alias.0.clone(),
+                    is_required: !matches!(ty, Type::Option(_)),
+                    ty,
+                    location: None,
+                    owner: None,
+                });
+            }
+        }
+        scope.is_grouped = false;
+        for omit in &self.0.omit {
+            diagnostics.extend(omit.diagnostics_for_type(rope, &Type::Any, &scope));
+        }
+        for transform in &self.0.transforms {
+            diagnostics.extend(transform.0.diagnostics(rope, &scope));
         }
         diagnostics
     }
@@ -54,47 +87,369 @@ impl HasDiagnostic for Spanned<&SelectStatement> {
 
 impl Typed for SelectStatement {
     fn get_type(&self, scope: &ScopedItems) -> Type {
+        if self.from.is_empty() {
+            return Type::Any;
+        }
+        if self
+            .transforms
+            .iter()
+            .any(|transform| matches!(transform.0, Transform::Explain))
+        {
+            return get_explain_type();
+        }
+        // The records of each target are selected separately, so the result
+        // holds whichever of their shapes a record has.
+        let item = self
+            .from
+            .iter()
+            .map(|from| self.get_item_type(from.0.get_definition(scope), scope))
+            .reduce(|a, b| a.get_shared_super_type(&b))
+            .unwrap_or(Type::Any);
+        if self.only {
+            item
+        } else {
+            Type::Array(Box::new(item))
+        }
+    }
+}
+
+impl SelectStatement {
+    /// The type of each record selected from a target defined as `table`.
+    fn get_item_type(&self, table: Option<Object>, scope: &ScopedItems) -> Type {
         let mut scope = scope.clone();
-        let mut fields = vec![];
-        if let Some(from) = &self.from {
-            if let Some(ty) = scope.table_definitions.get(&from.0) {
-                for field in &ty.fields {
-                    scope.scoped_table.fields.retain(|f| f.name != field.name);
-                    scope.scoped_table.fields.push(field.clone());
-                }
+        for field in table.iter().flat_map(|table| &table.fields) {
+            scope.scoped_table.fields.retain(|f| f.name != field.name);
+            scope.scoped_table.fields.push(field.clone());
+        }
+        scope.is_grouped = self.group().is_some();
+        let mut fields = get_projected_fields(&self.projections, table.as_ref(), &mut scope);
+        for omit in &self.omit {
+            if let Some(path) = get_projection_path(&omit.0) {
+                remove_field(&mut fields, &path);
             }
-            for projection in &self.projections {
-                let expr = &projection.0.expr;
-                if let Some(alias) = &projection.0.alias {
-                    scope.scoped_table.fields.retain(|f| f.name != alias.0);
-                    scope.scoped_table.fields.push(Field {
-                        name: alias.0.clone(),
-                        ty: expr.0.get_type(&scope),
-                        is_required: match expr.0.get_type(&scope) {
-                            Type::Option(_) => false,
-                            _ => true,
-                        },
-                    });
-                    fields.push(Field {
-                        name: alias.0.clone(),
-                        ty: expr.0.get_type(&scope),
-                        is_required: match expr.0.get_type(&scope) {
-                            Type::Option(_) => false,
-                            _ => true,
-                        },
-                    });
-                } else {
-                    if let Expression::Identifier(ident) = &expr.0 {
-                        if let Some(ty) =
-                            scope.scoped_table.fields.iter().find(|f| f.name == *ident)
-                        {
-                            fields.push(ty.clone());
+        }
+        for transform in &self.transforms {
+            match &transform.0 {
+                // Each record holds a single item of the split array.
+                Transform::Split(split) => {
+                    for field in &mut fields {
+                        if is_named(split, &field.name) {
+                            if let Type::Array(inner) = &field.ty {
+                                field.ty = *inner.clone();
+                            }
+                        }
+                    }
+                }
+                Transform::Fetch(fetch) => {
+                    for field in &mut fields {
+                        if is_named(fetch, &field.name) {
+                            field.ty = fetch_type(&field.ty, &scope);
                         }
                     }
                 }
+                _ => {}
+            }
+        }
+        match (self.value, self.projections.first()) {
+            (true, Some(projection)) => {
+                let path = match &projection.0.alias {
+                    Some(alias) => Some(vec![alias.0.clone()]),
+                    None => get_projection_path(&projection.0.expr.0),
+                };
+                path.and_then(|path| get_field_type(&fields, &path))
+                    .unwrap_or_else(|| projection.0.expr.0.get_type(&scope))
+            }
+            _ => Type::Object(Object {
+                fields,
+                location: None,
+                is_flexible: false,
+            }),
+        }
+    }
+}
+
+/// The fields `projections` return for each record of `table`, whose fields
+/// are in `scope`. Aliases are added to `scope` as they are read, so the
+/// projections after them can use them.
+pub fn get_projected_fields(
+    projections: &[Spanned<Projection>],
+    table: Option<&Object>,
+    scope: &mut ScopedItems,
+) -> Vec<Field> {
+    let mut fields = vec![];
+    for projection in projections {
+        let expr = &projection.0.expr;
+        if let Expression::All = expr.0 {
+            for field in table.iter().flat_map(|table| &table.fields) {
+                insert_field(&mut fields, &[], field.clone());
+            }
+            continue;
+        }
+        // Records of a defined table without the field have no value for
+        // it.
+        let ty = if table.is_some() && reads_missing_field(&expr.0, scope) {
+            Type::Null
+        } else {
+            expr.0.get_type(scope)
+        };
+        let path = match &projection.0.alias {
+            Some(alias) => {
+                scope.scoped_table.fields.retain(|f| f.name != alias.0);
+                scope.scoped_table.fields.push(Field {
+                    name: alias.0.clone(),
+                    is_required: !matches!(ty, Type::Option(_)),
+                    ty: ty.clone(),
+                    location: None,
+                    owner: None,
+                });
+                vec![alias.0.clone()]
+            }
+            None => match (get_projection_path(&expr.0), &expr.0) {
+                (Some(path), _) => path,
+                // Calls are returned under the name of the function.
+                (None, Expression::Call { name, .. }) => vec![name
+                    .iter()
+                    .map(|name| name.0.clone())
+                    .collect::<Vec<_>>()
+                    .join("::")],
+                (None, _) => continue,
+            },
+        };
+        if let Some((name, parents)) = path.split_last() {
+            insert_field(
+                &mut fields,
+                parents,
+                Field {
+                    name: name.clone(),
+                    is_required: !matches!(ty, Type::Option(_)),
+                    ty,
+                    location: None,
+                    owner: None,
+                },
+            );
+        }
+    }
+    fields
+}
+
+/// Checks that a projection of a grouped `SELECT` only reads the grouped
+/// fields, or reads other fields through aggregate functions.
+fn get_group_diagnostics(
+    projection: &Spanned<Projection>,
+    group: &[Spanned<Expression>],
+    rope: &Rope,
+) -> Vec<Diagnostic> {
+    let grouped = group
+        .iter()
+        .filter_map(|expr| get_projection_path(&expr.0))
+        .collect::<Vec<_>>();
+    if let Some(alias) = &projection.0.alias {
+        if grouped.iter().any(|path| *path == [alias.0.clone()]) {
+            return vec![];
+        }
+    }
+    get_ungrouped_fields(&projection.0.expr, &grouped)
+        .into_iter()
+        .map(|(name, span)| Diagnostic {
+            range: span_to_range(span, rope).unwrap(),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: format!(
+                "Field {} must be grouped or used in an aggregate function",
+                name
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// The fields `expr` reads outside of aggregate functions which aren't in
+/// `grouped`, so have no single value for a group of records.
+fn get_ungrouped_fields<'a>(
+    expr: &'a Spanned<Expression>,
+    grouped: &[Vec<String>],
+) -> Vec<(String, &'a Span)> {
+    if let Some(path) = get_projection_path(&expr.0) {
+        if grouped.iter().any(|group| path.starts_with(group)) {
+            return vec![];
+        }
+        return vec![(path.join("."), &expr.1)];
+    }
+    match &expr.0 {
+        Expression::All => vec![("*".to_string(), &expr.1)],
+        Expression::Access { expr, access } => {
+            let mut fields = get_ungrouped_fields(expr, grouped);
+            if let Access::Index(index) = access.0.as_ref() {
+                fields.extend(get_ungrouped_fields(index, grouped));
+            }
+            fields
+        }
+        Expression::Call { name, args } => {
+            let name = name
+                .iter()
+                .map(|name| name.0.clone())
+                .collect::<Vec<_>>()
+                .join("::");
+            if AGGREGATE_FUNCTIONS.contains(&name.as_str()) {
+                return vec![];
+            }
+            args.iter()
+                .flatten()
+                .flat_map(|arg| get_ungrouped_fields(arg, grouped))
+                .collect()
+        }
+        Expression::Binary { left, right, .. } => {
+            let mut fields = get_ungrouped_fields(left, grouped);
+            fields.extend(get_ungrouped_fields(right, grouped));
+            fields
+        }
+        Expression::Unary { expr, .. } | Expression::Cast { expr, .. } => {
+            get_ungrouped_fields(expr, grouped)
+        }
+        Expression::Array(items) => items
+            .iter()
+            .flat_map(|item| get_ungrouped_fields(item, grouped))
+            .collect(),
+        Expression::Object(entries) => entries
+            .iter()
+            .flat_map(|(entry, _)| match &entry.value {
+                Some(value) => get_ungrouped_fields(value, grouped),
+                // `{ field }` is short for `{ field: field }`.
+                None if grouped.iter().any(|group| *group == [entry.key.0.clone()]) => vec![],
+                None => vec![(entry.key.0.clone(), &entry.key.1)],
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// The path of the field a projection is returned in, e.g. `address.city`
+/// is returned as `{ address: { city } }`, `address.*` as `{ address }`, and
+/// `->likes->post.title` as `{ "->likes": { "->post": { title } } }`.
+fn get_projection_path(expr: &Expression) -> Option<Vec<String>> {
+    match expr {
+        Expression::Identifier(name) => Some(vec![name.clone()]),
+        Expression::Graph(graph) => Some(vec![get_graph_key(graph)]),
+        Expression::Access { expr, access } => {
+            let mut path = get_projection_path(&expr.0)?;
+            match access.0.as_ref() {
+                Access::Property(name) => path.push(name.clone()),
+                Access::Graph(graph) => path.push(get_graph_key(graph)),
+                Access::All => {}
+                Access::Index(_) => return None,
+            }
+            Some(path)
+        }
+        _ => None,
+    }
+}
+
+/// The key a step of a graph traversal is returned under, e.g. `->likes`.
+fn get_graph_key(graph: &Graph) -> String {
+    format!("{}{}", graph.direction, graph.table.0)
+}
+
+/// Whether `expr` reads a field which the scoped table doesn't have.
+fn reads_missing_field(expr: &Expression, scope: &ScopedItems) -> bool {
+    match expr {
+        Expression::Identifier(name) => scope.scoped_table.get_field(name).is_none(),
+        Expression::Access { expr, .. } => reads_missing_field(&expr.0, scope),
+        _ => false,
+    }
+}
+
+/// Adds `field` to `fields`, nested in the objects named by `parents`.
+fn insert_field(fields: &mut Vec<Field>, parents: &[String], field: Field) {
+    let Some((parent, rest)) = parents.split_first() else {
+        fields.retain(|f| f.name != field.name);
+        fields.push(field);
+        return;
+    };
+    let index = match fields.iter().position(|f| f.name == *parent) {
+        Some(index) => index,
+        None => {
+            fields.push(Field {
+                name: parent.clone(),
+                ty: Type::Object(Object {
+                    fields: vec![],
+                    location: None,
+                    is_flexible: false,
+                }),
+                is_required: true,
+                location: None,
+                owner: None,
+            });
+            fields.len() - 1
+        }
+    };
+    if !matches!(fields[index].ty, Type::Object(_)) {
+        fields[index].ty = Type::Object(Object {
+            fields: vec![],
+            location: None,
+            is_flexible: false,
+        });
+    }
+    if let Type::Object(obj) = &mut fields[index].ty {
+        insert_field(&mut obj.fields, rest, field);
+    }
+}
+
+fn remove_field(fields: &mut Vec<Field>, path: &[String]) {
+    match path {
+        [name] => fields.retain(|f| f.name != *name),
+        [name, rest @ ..] => {
+            if let Some(Field {
+                ty: Type::Object(obj),
+                ..
+            }) = fields.iter_mut().find(|f| f.name == *name)
+            {
+                remove_field(&mut obj.fields, rest);
             }
-            return Type::Array(Box::new(Type::Object(Object { fields })));
         }
-        Type::Any
+        [] => {}
     }
 }
+
+fn get_field_type(fields: &[Field], path: &[String]) -> Option<Type> {
+    let (name, rest) = path.split_first()?;
+    let field = fields.iter().find(|f| f.name == *name)?;
+    match (&field.ty, rest) {
+        (ty, []) => Some(ty.clone()),
+        (Type::Object(obj), rest) => get_field_type(&obj.fields, rest),
+        _ => None,
+    }
+}
+
+fn is_named(exprs: &[Spanned<Expression>], name: &str) -> bool {
+    exprs
+        .iter()
+        .any(|expr| matches!(&expr.0, Expression::Identifier(ident) if ident == name))
+}
+
+/// `ty` with its record links replaced by the records they point to.
+fn fetch_type(ty: &Type, scope: &ScopedItems) -> Type {
+    match ty {
+        Type::Record(table) => match scope.table_definitions.get(table) {
+            Some(obj) => Type::Object(obj.clone()),
+            None => ty.clone(),
+        },
+        Type::Array(inner) => Type::Array(Box::new(fetch_type(inner, scope))),
+        Type::Option(inner) => Type::Option(Box::new(fetch_type(inner, scope))),
+        _ => ty.clone(),
+    }
+}
+
+/// The query plan returned by `EXPLAIN`.
+fn get_explain_type() -> Type {
+    let field = |name: &str, ty: Type| Field {
+        name: name.to_string(),
+        ty,
+        is_required: true,
+        location: None,
+        owner: None,
+    };
+    Type::Array(Box::new(Type::Object(Object {
+        fields: vec![field("detail", Type::Any), field("operation", Type::String)],
+        location: None,
+        is_flexible: false,
+    })))
+}
@@ -1,11 +1,11 @@
 use ropey::Rope;
-use tower_lsp::lsp_types::Diagnostic;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 
 use crate::{
     ast::{expr::types::Typed, statement::statement::Statement},
     declarations::{scoped_item::ScopedItems, type_::Type},
     features::diagnostics::diagnostic::HasDiagnostic,
-    util::span::Spanned,
+    util::{range::span_to_range, span::Spanned},
 };
 
 impl HasDiagnostic for Spanned<Statement> {
@@ -13,11 +13,30 @@ impl HasDiagnostic for Spanned<Statement> {
         match &self.0 {
             Statement::Create(create) => (create, self.1).diagnostics(rope, scope),
             Statement::Update(update) => (update, self.1).diagnostics(rope, scope),
+            Statement::Upsert(upsert) => (upsert, self.1).diagnostics(rope, scope),
             Statement::Delete(delete) => (delete, self.1).diagnostics(rope, scope),
+            Statement::Insert(insert) => (insert, self.1).diagnostics(rope, scope),
+            Statement::Relate(relate) => (relate, self.1).diagnostics(rope, scope),
             Statement::Select(select) => (select, self.1).diagnostics(rope, scope),
             Statement::Define(define) => (&define.0, self.1).diagnostics(rope, scope),
             Statement::Return(expr) => expr.diagnostics(rope, scope),
             Statement::Let(let_) => (let_, self.1).diagnostics(rope, scope),
+            Statement::IfElse(if_else) => (if_else, self.1).diagnostics(rope, scope),
+            Statement::For(for_) => (for_, self.1).diagnostics(rope, scope),
+            Statement::Break | Statement::Continue if !scope.is_in_loop => vec![Diagnostic {
+                range: span_to_range(&self.1, rope).unwrap(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                message: format!(
+                    "{} can only be used inside a FOR loop",
+                    match self.0 {
+                        Statement::Break => "BREAK",
+                        _ => "CONTINUE",
+                    }
+                ),
+                ..Default::default()
+            }],
+            Statement::Break | Statement::Continue => vec![],
+            Statement::Throw(expr) | Statement::Expression(expr) => expr.diagnostics(rope, scope),
             Statement::Invalid => vec![],
         }
     }
@@ -28,8 +47,13 @@ impl Typed for Statement {
         match self {
             Statement::Create(create) => create.get_type(scope),
             Statement::Update(update) => update.get_type(scope),
+            Statement::Upsert(upsert) => upsert.get_type(scope),
             Statement::Delete(delete) => delete.get_type(scope),
+            Statement::Insert(insert) => insert.get_type(scope),
+            Statement::Relate(relate) => relate.get_type(scope),
             Statement::Select(select) => select.get_type(scope),
+            Statement::Return(expr) | Statement::Expression(expr) => expr.0.get_type(scope),
+            Statement::IfElse(if_else) => if_else.get_type(scope),
             _ => Type::Null,
         }
     }
@@ -0,0 +1,57 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{
+    ast::{expr::types::Typed, target::Target},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::HasDiagnostic,
+    util::range::span_to_range,
+};
+
+use super::table_name::diagnostics_for_table_name;
+
+impl HasDiagnostic for Target {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        if let Some(table) = self.table() {
+            diagnostics.extend(diagnostics_for_table_name(&table.0, &table.1, rope, scope));
+        }
+        for expr in self.expressions() {
+            diagnostics.extend(expr.diagnostics(rope, scope));
+        }
+        if let Target::Expression(expr) = self {
+            let ty = expr.0.get_type(scope);
+            if !is_target_type(&ty) {
+                diagnostics.push(Diagnostic {
+                    range: span_to_range(&expr.1, rope).unwrap(),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    message: format!("Expected a table or records, found type {}", ty),
+                    ..Default::default()
+                });
+            }
+        }
+        diagnostics
+    }
+}
+
+impl Typed for Target {
+    fn get_type(&self, scope: &ScopedItems) -> Type {
+        match self {
+            Target::Record(table, _) => Type::Record(table.0.clone()),
+            Target::Table(table) | Target::Range(table, _, _) => {
+                Type::Array(Box::new(Type::Record(table.0.clone())))
+            }
+            Target::Expression(expr) => expr.0.get_type(scope),
+        }
+    }
+}
+
+/// Whether a value of type `ty` can be the target of a statement. Strings
+/// are taken as table names.
+fn is_target_type(ty: &Type) -> bool {
+    match ty {
+        Type::Array(inner) | Type::Option(inner) => is_target_type(inner),
+        Type::Record(_) | Type::Object(_) | Type::String | Type::Any | Type::Error => true,
+        _ => false,
+    }
+}
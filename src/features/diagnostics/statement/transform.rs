@@ -20,6 +20,17 @@ impl HasDiagnostic for Transform {
             Transform::Skip(Some(skip)) => {
                 diagnostics.extend(skip.diagnostics_for_type(rope, &Type::Int, scope));
             }
+            Transform::Timeout(Some(timeout)) => {
+                diagnostics.extend(timeout.diagnostics_for_type(rope, &Type::Duration, scope));
+            }
+            Transform::Split(fields)
+            | Transform::Group(fields)
+            | Transform::Order(fields)
+            | Transform::Fetch(fields) => {
+                for field in fields {
+                    diagnostics.extend(field.diagnostics_for_type(rope, &Type::Any, scope));
+                }
+            }
             _ => {}
         }
         diagnostics
@@ -8,50 +8,51 @@ use crate::{
     util::span::Spanned,
 };
 
-use super::table_name::diagnostics_for_table_name;
-
 impl HasDiagnostic for Spanned<&UpdateStatement> {
     fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
         let mut diagnostics = vec![];
         let mut scope = scope.clone();
-        let ty = match &self.0.table {
-            Some(table) => {
-                diagnostics.extend(diagnostics_for_table_name(&table.0, &table.1, rope, &scope));
-                if let Some(obj) = scope.table_definitions.get(&table.0) {
-                    for field in &obj.fields {
-                        scope.scoped_table.fields.retain(|f| f.name != field.name);
-                        scope.scoped_table.fields.push(field.clone());
+        let ty = match &self.0.target {
+            Some(target) => {
+                diagnostics.extend(target.0.diagnostics(rope, &scope));
+                match target.0.get_definition(&scope) {
+                    Some(obj) => {
+                        for field in &obj.fields {
+                            scope.scoped_table.fields.retain(|f| f.name != field.name);
+                            scope.scoped_table.fields.push(field.clone());
+                        }
+                        Type::Object(obj)
                     }
-                    Type::Object(obj.clone())
-                } else {
-                    Type::Any
+                    None => Type::Any,
                 }
             }
             None => Type::Any,
         };
-        if let Some(content) = &self.0.content {
-            diagnostics.extend(content.diagnostics_for_type(rope, &ty, &scope));
+        if let Some(data) = &self.0.data {
+            diagnostics.extend(data.diagnostics_for_type(rope, &ty, &scope));
         };
 
         for transform in &self.0.transforms {
             diagnostics.extend(transform.0.diagnostics(rope, &scope));
         }
+        if let Some(output) = &self.0.output {
+            diagnostics.extend(output.0.diagnostics(rope, &scope));
+        }
         diagnostics
     }
 }
 
 impl Typed for UpdateStatement {
     fn get_type(&self, scope: &ScopedItems) -> Type {
-        let mut scope = scope.clone();
-        if let Some(table) = &self.table {
-            if let Some(obj) = scope.table_definitions.get(&table.0) {
-                for field in &obj.fields {
-                    scope.scoped_table.fields.retain(|f| f.name != field.name);
-                    scope.scoped_table.fields.push(field.clone());
-                }
-                return Type::Array(Box::new(Type::Object(obj.clone())));
-            }
-        }
-        Type::Any
+        let definition = self
+            .target
+            .as_ref()
+            .and_then(|target| target.0.get_definition(scope));
+        let item = match (&self.output, definition) {
+            (Some(output), definition) => output.0.get_item_type(definition.as_ref(), scope),
+            (None, Some(obj)) => Type::Object(obj),
+            (None, None) => return Type::Any,
+        };
+        Type::Array(Box::new(item))
     }
 }
@@ -0,0 +1,57 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::Diagnostic;
+
+use crate::{
+    ast::{expr::types::Typed, statement::crud::upsert::UpsertStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::{HasDiagnostic, HasDiagnosticsForType},
+    util::span::Spanned,
+};
+
+impl HasDiagnostic for Spanned<&UpsertStatement> {
+    fn diagnostics(&self, rope: &Rope, scope: &ScopedItems) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut scope = scope.clone();
+        let ty = match &self.0.target {
+            Some(target) => {
+                diagnostics.extend(target.0.diagnostics(rope, &scope));
+                match target.0.get_definition(&scope) {
+                    Some(obj) => {
+                        for field in &obj.fields {
+                            scope.scoped_table.fields.retain(|f| f.name != field.name);
+                            scope.scoped_table.fields.push(field.clone());
+                        }
+                        Type::Object(obj)
+                    }
+                    None => Type::Any,
+                }
+            }
+            None => Type::Any,
+        };
+        if let Some(data) = &self.0.data {
+            diagnostics.extend(data.diagnostics_for_type(rope, &ty, &scope));
+        }
+        for transform in &self.0.transforms {
+            diagnostics.extend(transform.0.diagnostics(rope, &scope));
+        }
+        if let Some(output) = &self.0.output {
+            diagnostics.extend(output.0.diagnostics(rope, &scope));
+        }
+        diagnostics
+    }
+}
+
+impl Typed for UpsertStatement {
+    fn get_type(&self, scope: &ScopedItems) -> Type {
+        let definition = self
+            .target
+            .as_ref()
+            .and_then(|target| target.0.get_definition(scope));
+        let item = match (&self.output, definition) {
+            (Some(output), definition) => output.0.get_item_type(definition.as_ref(), scope),
+            (None, Some(obj)) => Type::Object(obj),
+            (None, None) => return Type::Any,
+        };
+        Type::Array(Box::new(item))
+    }
+}
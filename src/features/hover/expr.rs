@@ -0,0 +1,161 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::expr::{
+        access::{Access, Graph},
+        object::ObjectEntry,
+        parser::Expression,
+        types::Typed,
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    util::{range::span_to_range, span::Spanned},
+};
+
+use super::{get_hover_for_field, get_hover_for_function, get_hover_for_type, has_hover::HasHover};
+
+impl HasHover for Spanned<Expression> {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let range = span_to_range(&self.1, rope).unwrap();
+        if !(range.start <= position && position <= range.end) {
+            return None;
+        }
+        let inner = match &self.0 {
+            Expression::Identifier(name) => {
+                if let Some(field) = scope.scoped_table.get_field(name) {
+                    return Some(get_hover_for_field(field, Some(range)));
+                }
+                None
+            }
+            Expression::Binary { left, right, .. } => left
+                .get_hover(scope, position, rope)
+                .or_else(|| right.get_hover(scope, position, rope)),
+            Expression::Unary { expr, .. } | Expression::Cast { expr, .. } => {
+                expr.get_hover(scope, position, rope)
+            }
+            Expression::Access { expr, access } => {
+                if let Some(hover) = expr.get_hover(scope, position, rope) {
+                    return Some(hover);
+                }
+                match access.0.as_ref() {
+                    Access::Property(name) => {
+                        let access_range = span_to_range(&access.1, rope).unwrap();
+                        let mut ty = expr.0.get_type(scope);
+                        while let Type::Array(inner) | Type::Option(inner) = ty {
+                            ty = *inner;
+                        }
+                        match scope.dereference(ty) {
+                            Type::Object(obj)
+                                if access_range.start <= position
+                                    && position <= access_range.end =>
+                            {
+                                obj.get_field(name)
+                                    .map(|field| get_hover_for_field(field, Some(access_range)))
+                            }
+                            _ => None,
+                        }
+                    }
+                    Access::Index(index) => index.get_hover(scope, position, rope),
+                    Access::Graph(graph) => get_hover_for_graph(graph, scope, position, rope),
+                    Access::All => None,
+                }
+            }
+            Expression::Graph(graph) => get_hover_for_graph(graph, scope, position, rope),
+            Expression::Array(items) => items
+                .iter()
+                .find_map(|item| item.get_hover(scope, position, rope)),
+            Expression::Object(entries) => {
+                get_hover_for_object(entries, &Type::Any, scope, position, rope)
+            }
+            Expression::Call { name, args } => {
+                if let Some(hover) = args
+                    .iter()
+                    .flatten()
+                    .find_map(|arg| arg.get_hover(scope, position, rope))
+                {
+                    return Some(hover);
+                }
+                let start = name.first().map(|name| name.1.start)?;
+                let end = name.last().map(|name| name.1.end)?;
+                let name_range = span_to_range(&(start..end).into(), rope).unwrap();
+                let name = name
+                    .iter()
+                    .map(|name| name.0.clone())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                match scope.functions.get(&name) {
+                    Some(function)
+                        if name_range.start <= position && position <= name_range.end =>
+                    {
+                        Some(get_hover_for_function(&name, function, Some(name_range)))
+                    }
+                    _ => None,
+                }
+            }
+            Expression::CodeBlock(block) => return block.get_hover(scope, position, rope),
+            Expression::Inline(statement) => {
+                let statement_range = span_to_range(&statement.1, rope).unwrap();
+                if statement_range.start <= position && position <= statement_range.end {
+                    statement.0.get_hover(scope, position, rope)
+                } else {
+                    None
+                }
+            }
+            Expression::Literal(_) | Expression::Variable(_) | Expression::All => None,
+        };
+        inner.or_else(|| Some(get_hover_for_type(&self.0.get_type(scope), Some(range))))
+    }
+}
+
+/// Hover inside the `[WHERE ...]` condition of a graph traversal step.
+fn get_hover_for_graph(
+    graph: &Graph,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    graph.condition.as_ref().and_then(|condition| {
+        condition.get_hover(&graph.get_condition_scope(scope), position, rope)
+    })
+}
+
+/// Hover for an object literal, where the keys name the fields of `type_`.
+pub fn get_hover_for_object(
+    entries: &Vec<Spanned<ObjectEntry>>,
+    type_: &Type,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    for (entry, _) in entries {
+        let field = match type_ {
+            Type::Object(obj) => obj.get_field(&entry.key.0),
+            _ => None,
+        };
+        let key_range = span_to_range(&entry.key.1, rope).unwrap();
+        if key_range.start <= position && position <= key_range.end {
+            return field.map(|field| get_hover_for_field(field, Some(key_range)));
+        }
+        let Some(value) = &entry.value else {
+            continue;
+        };
+        let hover = match (&value.0, field) {
+            (Expression::Object(inner), Some(field)) => {
+                let value_range = span_to_range(&value.1, rope).unwrap();
+                if !(value_range.start <= position && position <= value_range.end) {
+                    continue;
+                }
+                let mut ty = field.ty.clone();
+                while let Type::Option(inner) = ty {
+                    ty = *inner;
+                }
+                get_hover_for_object(inner, &ty, scope, position, rope)
+            }
+            _ => value.get_hover(scope, position, rope),
+        };
+        if hover.is_some() {
+            return hover;
+        }
+    }
+    None
+}
@@ -0,0 +1,33 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{expr::types::Typed, parser::File, statement::statement::Statement},
+    declarations::scoped_item::ScopedItems,
+    util::range::span_to_range,
+};
+
+use super::has_hover::HasHover;
+
+impl HasHover for File {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let mut scope = scope.clone();
+        for statement in self {
+            let range = span_to_range(&statement.1, rope).unwrap();
+            if range.start <= position && position <= range.end {
+                if let Some(hover) = statement.0.get_hover(&scope, position, rope) {
+                    return Some(hover);
+                }
+            }
+            if let Statement::Let(let_) = &statement.0 {
+                if let Some(name) = &let_.name {
+                    if let Some(value) = &let_.value {
+                        let ty = value.0.get_type(&scope);
+                        scope.variables.insert(name.0.clone(), ty);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,8 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::declarations::scoped_item::ScopedItems;
+
+pub trait HasHover {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover>;
+}
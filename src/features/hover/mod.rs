@@ -0,0 +1,113 @@
+use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind, Range};
+
+use crate::{
+    declarations::{field::Field, functions::Function, type_::Type},
+    ls::backend::Backend,
+};
+
+use self::has_hover::HasHover;
+
+pub mod expr;
+pub mod file;
+pub mod has_hover;
+pub mod statements;
+
+pub async fn get_hover(backend: &Backend, params: HoverParams) -> Option<Hover> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rope = backend.document_map.get(uri.as_str())?.value().clone();
+    let ast = backend.ast_map.get(uri.as_str())?.value().clone();
+    let scope = backend.state.lock().await.clone();
+    ast.get_hover(&scope, position, &rope)
+}
+
+fn markdown_hover(value: String, range: Option<Range>) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range,
+    }
+}
+
+pub fn get_hover_for_type(type_: &Type, range: Option<Range>) -> Hover {
+    markdown_hover(format!("```surql\n{}\n```", type_), range)
+}
+
+pub fn get_hover_for_field(field: &Field, range: Option<Range>) -> Hover {
+    let required = if field.is_required {
+        "Required field"
+    } else {
+        "Optional field"
+    };
+    markdown_hover(
+        format!("```surql\n{}: {}\n```\n{}", field.name, field.ty, required),
+        range,
+    )
+}
+
+pub fn get_hover_for_function(name: &str, function: &Function, range: Option<Range>) -> Hover {
+    let signatures = function
+        .overloads
+        .iter()
+        .map(|signature| format!("{}{}", name, signature))
+        .collect::<Vec<_>>();
+    let mut value = format!("```surql\n{}\n```", signatures.join("\n"));
+    if let Some(doc) = &function.doc {
+        value.push_str("\n---\n");
+        value.push_str(&trim_indent(doc));
+    }
+    markdown_hover(value, range)
+}
+
+/// Removes the indentation shared by every line of `text`, so docs written in
+/// indented raw strings render as markdown rather than code blocks.
+fn trim_indent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    text.lines()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{HoverContents, Position};
+
+    use crate::util::testing::{parse, scope, SCHEMA};
+
+    use super::has_hover::HasHover;
+
+    #[test]
+    fn expressions_fields_and_functions_are_described() {
+        let scope = scope(SCHEMA);
+        let src = "LET $p = 1;\nSELECT name, string::len(name) FROM person WHERE age > $p;";
+        let rope = Rope::from_str(src);
+        let file = parse(src);
+        let hover = |character| {
+            let hover = file.get_hover(&scope, Position::new(1, character), &rope)?;
+            match hover.contents {
+                HoverContents::Markup(markup) => Some(markup.value),
+                contents => panic!("unexpected hover {contents:?}"),
+            }
+        };
+        let name = Some("```surql\nname: string\n```\nRequired field".to_string());
+        assert_eq!(hover(8), name);
+        assert_eq!(hover(26), name);
+        let len = hover(15).unwrap();
+        assert!(len.starts_with("```surql\nstring::len(string: string) -> int\n```\n---\n"));
+        assert!(len.contains("Returns the length of a string."));
+        assert_eq!(hover(53), Some("```surql\nbool\n```".to_string()));
+        assert_eq!(hover(56), Some("```surql\nint\n```".to_string()));
+        assert_eq!(hover(45), None);
+    }
+}
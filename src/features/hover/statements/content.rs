@@ -0,0 +1,50 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{
+        statement::{crud::data::Data, transform::Transform},
+        target::Target,
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::has_hover::HasHover,
+    util::span::Spanned,
+};
+
+use super::data::get_hover_for_data;
+
+/// Hover for a statement which writes `data` to `target`, such as `CREATE` or
+/// `UPDATE`.
+pub fn get_hover_for_content(
+    target: &Option<Spanned<Target>>,
+    data: &Option<Spanned<Data>>,
+    transforms: &[Spanned<Transform>],
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    let mut scope = scope.clone();
+    let mut ty = Type::Any;
+    if let Some(target) = target {
+        let hover = target.0.get_hover(&scope, position, rope);
+        if hover.is_some() {
+            return hover;
+        }
+        if let Some(obj) = target.0.get_definition(&scope) {
+            for field in &obj.fields {
+                scope.scoped_table.fields.retain(|f| f.name != field.name);
+                scope.scoped_table.fields.push(field.clone());
+            }
+            ty = Type::Object(obj);
+        }
+    }
+    if let Some(data) = data {
+        let hover = get_hover_for_data(&data.0, &ty, &scope, position, rope);
+        if hover.is_some() {
+            return hover;
+        }
+    }
+    transforms
+        .iter()
+        .find_map(|transform| transform.0.get_hover(&scope, position, rope))
+}
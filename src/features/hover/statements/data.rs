@@ -0,0 +1,70 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        statement::crud::data::{Assignment, Data},
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::{expr::get_hover_for_object, get_hover_for_field, has_hover::HasHover},
+    util::{range::span_to_range, span::Spanned},
+};
+
+/// Hover for the data written to a record of type `ty`.
+pub fn get_hover_for_data(
+    data: &Data,
+    ty: &Type,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    match data {
+        Data::Content(Some(content))
+        | Data::Replace(Some(content))
+        | Data::Merge(Some(content)) => match &content.0 {
+            Expression::Object(entries) => get_hover_for_object(entries, ty, scope, position, rope),
+            _ => content.get_hover(scope, position, rope),
+        },
+        Data::Patch(Some(patch)) => patch.get_hover(scope, position, rope),
+        Data::Content(None) | Data::Replace(None) | Data::Merge(None) | Data::Patch(None) => None,
+        Data::Set(assignments) => get_hover_for_assignments(assignments, ty, scope, position, rope),
+        Data::Unset(fields) => fields.iter().find_map(|path| {
+            let mut ty = ty.clone();
+            for (name, span) in path {
+                let field = ty.get_field(name).cloned()?;
+                let range = span_to_range(span, rope).unwrap();
+                if range.start <= position && position <= range.end {
+                    return Some(get_hover_for_field(&field, Some(range)));
+                }
+                ty = field.ty;
+            }
+            None
+        }),
+    }
+}
+
+/// Hover for the field paths and values of `assignments` to a record of
+/// type `ty`.
+pub fn get_hover_for_assignments(
+    assignments: &[Spanned<Assignment>],
+    ty: &Type,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    assignments.iter().find_map(|(assignment, _)| {
+        let mut ty = ty.clone();
+        for (name, span) in &assignment.field {
+            let Some(field) = ty.get_field(name).cloned() else {
+                break;
+            };
+            let range = span_to_range(span, rope).unwrap();
+            if range.start <= position && position <= range.end {
+                return Some(get_hover_for_field(&field, Some(range)));
+            }
+            ty = field.ty;
+        }
+        assignment.value.get_hover(scope, position, rope)
+    })
+}
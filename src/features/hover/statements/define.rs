@@ -0,0 +1,36 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::define::DefineStatement,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::{get_hover_for_field, has_hover::HasHover},
+    util::range::span_to_range,
+};
+
+impl HasHover for DefineStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let DefineStatement::Field(field) = self else {
+            return None;
+        };
+        let table = scope
+            .table_definitions
+            .get(&field.0.table_name.as_ref()?.0)?;
+        let mut ty = Type::Object(table.clone());
+        for name in field.0.parents.iter().chain([&field.0.name]) {
+            while let Type::Array(inner) | Type::Option(inner) = ty {
+                ty = *inner;
+            }
+            let Type::Object(obj) = &ty else {
+                return None;
+            };
+            let found = obj.get_field(&name.0)?;
+            let name_range = span_to_range(&name.1, rope).unwrap();
+            if name_range.start <= position && position <= name_range.end {
+                return Some(get_hover_for_field(found, Some(name_range)));
+            }
+            ty = found.ty.clone();
+        }
+        None
+    }
+}
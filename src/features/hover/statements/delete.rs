@@ -0,0 +1,15 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::crud::delete::DeleteStatement, declarations::scoped_item::ScopedItems,
+    features::hover::has_hover::HasHover,
+};
+
+use super::content::get_hover_for_content;
+
+impl HasHover for DeleteStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        get_hover_for_content(&self.target, &None, &self.transforms, scope, position, rope)
+    }
+}
@@ -0,0 +1,23 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::for_::ForStatement,
+    declarations::scoped_item::ScopedItems,
+    features::hover::{get_hover_for_type, has_hover::HasHover},
+    util::range::span_to_range,
+};
+
+impl HasHover for ForStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let variable_range = span_to_range(&self.variable.1, rope).unwrap();
+        if variable_range.start <= position && position <= variable_range.end {
+            let ty = self.get_item_type(scope);
+            return Some(get_hover_for_type(&ty, Some(variable_range)));
+        }
+        self.iterable.get_hover(scope, position, rope).or_else(|| {
+            self.body
+                .get_hover(&self.get_body_scope(scope), position, rope)
+        })
+    }
+}
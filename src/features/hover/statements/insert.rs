@@ -0,0 +1,89 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        statement::crud::{
+            insert::{InsertStatement, InsertValues},
+            output::Output,
+        },
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::{expr::get_hover_for_object, get_hover_for_field, has_hover::HasHover},
+    util::{range::span_to_range, span::Spanned},
+};
+
+use super::data::get_hover_for_assignments;
+
+impl HasHover for InsertStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let mut scope = scope.clone();
+        let mut ty = Type::Any;
+        if let Some(table) = &self.table {
+            if let Some(obj) = scope.table_definitions.get(&table.0) {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                ty = Type::Object(obj.clone());
+            }
+        }
+        let hover = match &self.values {
+            Some((InsertValues::Content(content), _)) => {
+                get_hover_for_record(content, &ty, &scope, position, rope)
+            }
+            Some((InsertValues::Values { fields, rows }, _)) => fields
+                .iter()
+                .find_map(|(name, span)| {
+                    let range = span_to_range(span, rope).unwrap();
+                    match ty.get_field(name) {
+                        Some(field) if range.start <= position && position <= range.end => {
+                            Some(get_hover_for_field(field, Some(range)))
+                        }
+                        _ => None,
+                    }
+                })
+                .or_else(|| {
+                    rows.iter()
+                        .flat_map(|row| &row.0)
+                        .find_map(|value| value.get_hover(&scope, position, rope))
+                }),
+            None => None,
+        };
+        if hover.is_some() {
+            return hover;
+        }
+        if let Some(assignments) = &self.on_duplicate {
+            let mut scope = scope.clone();
+            scope.variables.insert("input".to_string(), ty.clone());
+            let hover = get_hover_for_assignments(assignments, &ty, &scope, position, rope);
+            if hover.is_some() {
+                return hover;
+            }
+        }
+        match &self.output {
+            Some((Output::Fields(projections), _)) => projections
+                .iter()
+                .find_map(|projection| projection.0.expr.get_hover(&scope, position, rope)),
+            _ => None,
+        }
+    }
+}
+
+/// Hover for a record of type `ty`, or an array of them, being inserted.
+fn get_hover_for_record(
+    record: &Spanned<Expression>,
+    ty: &Type,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Hover> {
+    match &record.0 {
+        Expression::Object(entries) => get_hover_for_object(entries, ty, scope, position, rope),
+        Expression::Array(items) => items
+            .iter()
+            .find_map(|item| get_hover_for_record(item, ty, scope, position, rope)),
+        _ => record.get_hover(scope, position, rope),
+    }
+}
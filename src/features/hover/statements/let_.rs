@@ -0,0 +1,27 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{expr::types::Typed, statement::let_::LetStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::{get_hover_for_type, has_hover::HasHover},
+    util::range::span_to_range,
+};
+
+impl HasHover for LetStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        if let Some(name) = &self.name {
+            let name_range = span_to_range(&name.1, rope).unwrap();
+            if name_range.start <= position && position <= name_range.end {
+                let ty = match &self.value {
+                    Some(value) => value.0.get_type(scope),
+                    None => Type::Any,
+                };
+                return Some(get_hover_for_type(&ty, Some(name_range)));
+            }
+        }
+        self.value
+            .as_ref()
+            .and_then(|value| value.get_hover(scope, position, rope))
+    }
+}
@@ -0,0 +1,44 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::crud::{output::Output, relate::RelateStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::hover::has_hover::HasHover,
+};
+
+use super::data::get_hover_for_data;
+
+impl HasHover for RelateStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let mut scope = scope.clone();
+        for node in [&self.from, &self.to].into_iter().flatten() {
+            if let Some(hover) = node.0.get_hover(&scope, position, rope) {
+                return Some(hover);
+            }
+        }
+        let ty = match self.get_edge_definition(&scope) {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj)
+            }
+            None => Type::Any,
+        };
+        if let Some(hover) = self
+            .data
+            .as_ref()
+            .and_then(|data| get_hover_for_data(&data.0, &ty, &scope, position, rope))
+        {
+            return Some(hover);
+        }
+        match &self.output {
+            Some((Output::Fields(projections), _)) => projections
+                .iter()
+                .find_map(|projection| projection.0.expr.get_hover(&scope, position, rope)),
+            _ => None,
+        }
+    }
+}
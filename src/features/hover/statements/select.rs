@@ -0,0 +1,52 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::{expr::types::Typed, statement::crud::select::SelectStatement},
+    declarations::{field::Field, scoped_item::ScopedItems, type_::Type},
+    features::hover::has_hover::HasHover,
+};
+
+impl HasHover for SelectStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let mut scope = scope.clone();
+        for from in &self.from {
+            if let Some(hover) = from.0.get_hover(&scope, position, rope) {
+                return Some(hover);
+            }
+            if let Some(table) = from.0.get_definition(&scope) {
+                for field in &table.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+            }
+        }
+        for projection in &self.projections {
+            let expr = &projection.0.expr;
+            if let Some(hover) = expr.get_hover(&scope, position, rope) {
+                return Some(hover);
+            }
+            if let Some(alias) = &projection.0.alias {
+                let ty = expr.0.get_type(&scope);
+                scope.scoped_table.fields.retain(|f| f.name != alias.0);
+                scope.scoped_table.fields.push(Field {
+                    name: alias.0.clone(),
+                    is_required: !matches!(ty, Type::Option(_)),
+                    ty,
+                    location: None,
+                    owner: None,
+                });
+            }
+        }
+        if let Some(hover) = self
+            .omit
+            .iter()
+            .find_map(|omit| omit.get_hover(&scope, position, rope))
+        {
+            return Some(hover);
+        }
+        self.transforms
+            .iter()
+            .find_map(|transform| transform.0.get_hover(&scope, position, rope))
+    }
+}
@@ -0,0 +1,31 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::statement::Statement, declarations::scoped_item::ScopedItems,
+    features::hover::has_hover::HasHover,
+};
+
+impl HasHover for Statement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        match self {
+            Statement::Create(create) => create.get_hover(scope, position, rope),
+            Statement::Update(update) => update.get_hover(scope, position, rope),
+            Statement::Upsert(upsert) => upsert.get_hover(scope, position, rope),
+            Statement::Delete(delete) => delete.get_hover(scope, position, rope),
+            Statement::Insert(insert) => insert.get_hover(scope, position, rope),
+            Statement::Relate(relate) => relate.get_hover(scope, position, rope),
+            Statement::Select(select) => select.get_hover(scope, position, rope),
+            Statement::Return(expr) => expr.get_hover(scope, position, rope),
+            Statement::Define(define) => define.0.get_hover(scope, position, rope),
+            Statement::Let(let_) => let_.get_hover(scope, position, rope),
+            Statement::IfElse(if_else) => if_else.get_hover(scope, position, rope),
+            Statement::For(for_) => for_.get_hover(scope, position, rope),
+            Statement::Throw(expr) | Statement::Expression(expr) => {
+                expr.get_hover(scope, position, rope)
+            }
+            Statement::Break | Statement::Continue => None,
+            Statement::Invalid => None,
+        }
+    }
+}
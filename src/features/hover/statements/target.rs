@@ -0,0 +1,15 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::target::Target, declarations::scoped_item::ScopedItems,
+    features::hover::has_hover::HasHover,
+};
+
+impl HasHover for Target {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        self.expressions()
+            .into_iter()
+            .find_map(|expr| expr.get_hover(scope, position, rope))
+    }
+}
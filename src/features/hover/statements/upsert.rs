@@ -0,0 +1,43 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Hover, Position};
+
+use crate::{
+    ast::statement::crud::{output::Output, upsert::UpsertStatement},
+    declarations::scoped_item::ScopedItems,
+    features::hover::has_hover::HasHover,
+};
+
+use super::content::get_hover_for_content;
+
+impl HasHover for UpsertStatement {
+    fn get_hover(&self, scope: &ScopedItems, position: Position, rope: &Rope) -> Option<Hover> {
+        let hover = get_hover_for_content(
+            &self.target,
+            &self.data,
+            &self.transforms,
+            scope,
+            position,
+            rope,
+        );
+        if hover.is_some() {
+            return hover;
+        }
+        let mut scope = scope.clone();
+        if let Some(obj) = self
+            .target
+            .as_ref()
+            .and_then(|target| target.0.get_definition(&scope))
+        {
+            for field in &obj.fields {
+                scope.scoped_table.fields.retain(|f| f.name != field.name);
+                scope.scoped_table.fields.push(field.clone());
+            }
+        }
+        match &self.output {
+            Some((Output::Fields(projections), _)) => projections
+                .iter()
+                .find_map(|projection| projection.0.expr.get_hover(&scope, position, rope)),
+            _ => None,
+        }
+    }
+}
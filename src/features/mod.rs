@@ -1,3 +1,7 @@
 pub mod completions;
+pub mod definition;
 pub mod diagnostics;
+pub mod hover;
+pub mod references;
+pub mod rename;
 pub mod symbols;
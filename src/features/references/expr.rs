@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::expr::{
+        access::{Access, Graph},
+        object::ObjectEntry,
+        parser::Expression,
+        types::Typed,
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    util::span::{Span, Spanned},
+};
+
+use super::has_references::{HasReferences, Reference};
+
+impl HasReferences for Spanned<Expression> {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        match &self.0 {
+            Expression::Identifier(name) => match scope.scoped_table.get_field(name) {
+                Some(field) => vec![(Reference::Field(Box::new(field.clone())), self.1)],
+                None => vec![],
+            },
+            Expression::Variable(name) => match variables.get(name) {
+                Some(declaration) => vec![(
+                    Reference::Variable {
+                        declaration: *declaration,
+                    },
+                    self.1,
+                )],
+                None => vec![],
+            },
+            Expression::Access { expr, access } => {
+                let mut references = expr.get_references(scope, variables);
+                match access.0.as_ref() {
+                    Access::Property(name) => {
+                        let mut ty = expr.0.get_type(scope);
+                        while let Type::Array(inner) | Type::Option(inner) = ty {
+                            ty = *inner;
+                        }
+                        if let Type::Object(obj) = scope.dereference(ty) {
+                            if let Some(field) = obj.get_field(name) {
+                                references
+                                    .push((Reference::Field(Box::new(field.clone())), access.1));
+                            }
+                        }
+                    }
+                    Access::Index(index) => {
+                        references.extend(index.get_references(scope, variables));
+                    }
+                    Access::Graph(graph) => {
+                        references.extend(get_references_for_graph(graph, scope, variables));
+                    }
+                    Access::All => {}
+                }
+                references
+            }
+            Expression::Graph(graph) => get_references_for_graph(graph, scope, variables),
+            Expression::Binary { left, right, .. } => {
+                let mut references = left.get_references(scope, variables);
+                references.extend(right.get_references(scope, variables));
+                references
+            }
+            Expression::Unary { expr, .. } | Expression::Cast { expr, .. } => {
+                expr.get_references(scope, variables)
+            }
+            Expression::Array(items) => items
+                .iter()
+                .flat_map(|item| item.get_references(scope, variables))
+                .collect(),
+            Expression::Object(entries) => {
+                get_references_for_object(entries, &Type::Any, scope, variables)
+            }
+            Expression::Call { args, .. } => args
+                .iter()
+                .flatten()
+                .flat_map(|arg| arg.get_references(scope, variables))
+                .collect(),
+            Expression::CodeBlock(block) => block.get_references(scope, variables),
+            Expression::Inline(statement) => statement.get_references(scope, variables),
+            Expression::Literal(_) | Expression::All => vec![],
+        }
+    }
+}
+
+/// References in a graph traversal step: its table and the fields used in
+/// its `[WHERE ...]` condition.
+fn get_references_for_graph(
+    graph: &Graph,
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    let mut references = vec![(Reference::Table(graph.table.0.clone()), graph.table.1)];
+    if let Some(condition) = &graph.condition {
+        references.extend(condition.get_references(&graph.get_condition_scope(scope), variables));
+    }
+    references
+}
+
+/// References in an object literal, where the keys name the fields of `type_`.
+pub fn get_references_for_object(
+    entries: &Vec<Spanned<ObjectEntry>>,
+    type_: &Type,
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    let mut references = vec![];
+    for (entry, _) in entries {
+        let field = match type_ {
+            Type::Object(obj) => obj.get_field(&entry.key.0),
+            _ => None,
+        };
+        if let Some(field) = field {
+            references.push((Reference::Field(Box::new(field.clone())), entry.key.1));
+        }
+        let Some(value) = &entry.value else {
+            continue;
+        };
+        match (&value.0, field) {
+            (Expression::Object(inner), Some(field)) => {
+                let mut ty = field.ty.clone();
+                while let Type::Option(inner) = ty {
+                    ty = *inner;
+                }
+                references.extend(get_references_for_object(inner, &ty, scope, variables));
+            }
+            _ => references.extend(value.get_references(scope, variables)),
+        }
+    }
+    references
+}
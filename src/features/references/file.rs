@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{expr::types::Typed, parser::File, statement::statement::Statement},
+    declarations::scoped_item::ScopedItems,
+    util::span::{Span, Spanned},
+};
+
+use super::has_references::{HasReferences, Reference};
+
+impl HasReferences for File {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = Vec::new();
+        let mut scope = scope.clone();
+        let mut variables = variables.clone();
+        for statement in self {
+            references.extend(statement.get_references(&scope, &variables));
+            if let Statement::Let(let_) = &statement.0 {
+                if let Some(name) = &let_.name {
+                    if let Some(value) = &let_.value {
+                        let ty = value.0.get_type(&scope);
+                        scope.variables.insert(name.0.clone(), ty);
+                    }
+                    variables.insert(name.0.clone(), name.1);
+                }
+            }
+        }
+        references
+    }
+}
@@ -0,0 +1,136 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{Location, Position, ReferenceParams, Url};
+
+use crate::{
+    ast::parser::File,
+    declarations::scoped_item::ScopedItems,
+    ls::{backend::Backend, workspace::get_workspace_files},
+    util::{range::span_to_range, span::Spanned},
+};
+
+use super::has_references::{HasReferences, Reference};
+
+pub async fn get_references(backend: &Backend, params: ReferenceParams) -> Option<Vec<Location>> {
+    let uri = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let rope = backend.document_map.get(uri.as_str())?.value().clone();
+    let ast = backend.ast_map.get(uri.as_str())?.value().clone();
+    let scope = backend.state.lock().await.clone();
+    let (reference, _) = get_reference_at(&ast, &scope, position, &rope)?;
+    let mut locations = find_references(backend, &uri, &reference, &scope);
+    if !params.context.include_declaration {
+        let declaration = get_declaration(&reference, &uri, &rope, &scope);
+        locations.retain(|location| Some(location) != declaration.as_ref());
+    }
+    Some(locations)
+}
+
+/// The innermost reference in `file` which contains `position`.
+pub fn get_reference_at(
+    file: &File,
+    scope: &ScopedItems,
+    position: Position,
+    rope: &Rope,
+) -> Option<Spanned<Reference>> {
+    file.get_references(scope, &Default::default())
+        .into_iter()
+        .filter(|(_, span)| {
+            span_to_range(span, rope)
+                .is_some_and(|range| range.start <= position && position <= range.end)
+        })
+        .min_by_key(|(_, span)| span.end - span.start)
+}
+
+/// Where the symbol `reference` refers to is declared. `uri` and `rope` are
+/// the document containing the reference.
+pub fn get_declaration(
+    reference: &Reference,
+    uri: &Url,
+    rope: &Rope,
+    scope: &ScopedItems,
+) -> Option<Location> {
+    match reference {
+        Reference::Table(name) => scope.table_definitions.get(name)?.location.clone(),
+        Reference::Field(field) => field.location.clone(),
+        Reference::Variable { declaration } => Some(Location::new(
+            uri.clone(),
+            span_to_range(declaration, rope)?,
+        )),
+    }
+}
+
+/// Every location in the workspace which refers to the same symbol as
+/// `reference`, found in the document `uri`. Variables are only searched for
+/// in the document which declares them.
+pub fn find_references(
+    backend: &Backend,
+    uri: &Url,
+    reference: &Reference,
+    scope: &ScopedItems,
+) -> Vec<Location> {
+    let mut locations = vec![];
+    for (document, rope, file) in get_workspace_files(backend) {
+        if let Reference::Variable { .. } = reference {
+            if &document != uri {
+                continue;
+            }
+        }
+        for (other, span) in file.get_references(scope, &Default::default()) {
+            if !is_same_symbol(reference, &other) {
+                continue;
+            }
+            if let Some(range) = span_to_range(&span, &rope) {
+                locations.push(Location::new(document.clone(), range));
+            }
+        }
+    }
+    locations
+}
+
+fn is_same_symbol(reference: &Reference, other: &Reference) -> bool {
+    match (reference, other) {
+        (Reference::Table(name), Reference::Table(other)) => name == other,
+        (Reference::Field(field), Reference::Field(other)) => match (&field.location, &field.owner)
+        {
+            (Some(location), _) => other.location.as_ref() == Some(location),
+            (None, Some(owner)) => field.name == other.name && other.owner.as_ref() == Some(owner),
+            (None, None) => false,
+        },
+        (Reference::Variable { declaration }, Reference::Variable { declaration: other }) => {
+            declaration == other
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        features::references::has_references::{HasReferences, Reference},
+        util::testing::{parse, scope, SCHEMA},
+    };
+
+    use super::is_same_symbol;
+
+    #[test]
+    fn fields_without_a_location_match_by_table_and_path() {
+        let schema = format!("{SCHEMA} DEFINE TABLE tag; DEFINE FIELD name ON tag TYPE string;");
+        let scope = scope(&schema);
+        let src = "SELECT name FROM person; UPDATE person SET name = 'a'; SELECT name FROM tag";
+        let fields = parse(src)
+            .get_references(&scope, &Default::default())
+            .into_iter()
+            .filter_map(|(reference, span)| match reference {
+                Reference::Field(_) => Some((reference, span)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fields.len(), 3);
+        assert!(fields.iter().all(|(field, _)| match field {
+            Reference::Field(field) => field.location.is_none(),
+            _ => false,
+        }));
+        assert!(is_same_symbol(&fields[0].0, &fields[1].0));
+        assert!(!is_same_symbol(&fields[0].0, &fields[2].0));
+    }
+}
@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+use crate::{
+    declarations::{field::Field, scoped_item::ScopedItems},
+    util::span::{Span, Spanned},
+};
+
+/// Something a name in the source refers to.
+#[derive(Clone, Debug)]
+pub enum Reference {
+    Table(String),
+    Field(Box<Field>),
+    Variable { declaration: Span },
+}
+
+pub trait HasReferences {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>>;
+}
@@ -0,0 +1,5 @@
+pub mod expr;
+pub mod file;
+pub mod find;
+pub mod has_references;
+pub mod statements;
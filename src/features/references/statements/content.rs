@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{
+        statement::{crud::data::Data, transform::Transform},
+        target::Target,
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+use super::data::get_references_for_data;
+
+/// References in a statement which writes `data` to `target`, such as
+/// `CREATE` or `UPDATE`.
+pub fn get_references_for_content(
+    target: &Option<Spanned<Target>>,
+    data: &Option<Spanned<Data>>,
+    transforms: &[Spanned<Transform>],
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    let mut references = vec![];
+    let mut scope = scope.clone();
+    let mut ty = Type::Any;
+    if let Some(target) = target {
+        references.extend(target.0.get_references(&scope, variables));
+        if let Some(obj) = target.0.get_definition(&scope) {
+            for field in &obj.fields {
+                scope.scoped_table.fields.retain(|f| f.name != field.name);
+                scope.scoped_table.fields.push(field.clone());
+            }
+            ty = Type::Object(obj);
+        }
+    }
+    if let Some(data) = data {
+        references.extend(get_references_for_data(&data.0, &ty, &scope, variables));
+    }
+    for transform in transforms {
+        references.extend(transform.0.get_references(&scope, variables));
+    }
+    references
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::crud::{create::CreateStatement, output::Output},
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+use super::content::get_references_for_content;
+
+impl HasReferences for CreateStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = get_references_for_content(
+            &self.target,
+            &self.data,
+            &self.transforms,
+            scope,
+            variables,
+        );
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            let mut scope = scope.clone();
+            if let Some(obj) = self
+                .target
+                .as_ref()
+                .and_then(|target| target.0.get_definition(&scope))
+            {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+            }
+            for projection in projections {
+                references.extend(projection.0.expr.get_references(&scope, variables));
+            }
+        }
+        references
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        statement::crud::data::{Assignment, Data},
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::references::{
+        expr::get_references_for_object,
+        has_references::{HasReferences, Reference},
+    },
+    util::span::{Span, Spanned},
+};
+
+/// References in the data written to a record of type `ty`.
+pub fn get_references_for_data(
+    data: &Data,
+    ty: &Type,
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    let mut references = vec![];
+    match data {
+        Data::Content(Some(content))
+        | Data::Replace(Some(content))
+        | Data::Merge(Some(content)) => match &content.0 {
+            Expression::Object(entries) => {
+                references.extend(get_references_for_object(entries, ty, scope, variables))
+            }
+            _ => references.extend(content.get_references(scope, variables)),
+        },
+        Data::Patch(Some(patch)) => references.extend(patch.get_references(scope, variables)),
+        Data::Content(None) | Data::Replace(None) | Data::Merge(None) | Data::Patch(None) => {}
+        Data::Unset(fields) => {
+            for path in fields {
+                let mut ty = ty.clone();
+                for (name, span) in path {
+                    ty = match ty.get_field(name) {
+                        Some(field) => {
+                            references.push((Reference::Field(Box::new(field.clone())), *span));
+                            field.ty.clone()
+                        }
+                        None => Type::Any,
+                    };
+                }
+            }
+        }
+        Data::Set(assignments) => {
+            references.extend(get_references_for_assignments(
+                assignments,
+                ty,
+                scope,
+                variables,
+            ));
+        }
+    }
+    references
+}
+
+/// References in the field paths and values of `assignments` to a record of
+/// type `ty`.
+pub fn get_references_for_assignments(
+    assignments: &[Spanned<Assignment>],
+    ty: &Type,
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    let mut references = vec![];
+    for (assignment, _) in assignments {
+        let mut ty = ty.clone();
+        for (name, span) in &assignment.field {
+            ty = match ty.get_field(name) {
+                Some(field) => {
+                    references.push((Reference::Field(Box::new(field.clone())), *span));
+                    field.ty.clone()
+                }
+                None => Type::Any,
+            };
+        }
+        references.extend(assignment.value.get_references(scope, variables));
+    }
+    references
+}
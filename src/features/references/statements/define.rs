@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::define::DefineStatement,
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for DefineStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        _variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        match self {
+            DefineStatement::Table(table) => {
+                vec![(Reference::Table(table.0.name.0.clone()), table.0.name.1)]
+            }
+            DefineStatement::Field(field) => {
+                let mut references = vec![];
+                let Some(table_name) = &field.0.table_name else {
+                    return references;
+                };
+                references.push((Reference::Table(table_name.0.clone()), table_name.1));
+                let Some(table) = scope.table_definitions.get(&table_name.0) else {
+                    return references;
+                };
+                let mut ty = Type::Object(table.clone());
+                for name in field.0.parents.iter().chain([&field.0.name]) {
+                    while let Type::Array(inner) | Type::Option(inner) = ty {
+                        ty = *inner;
+                    }
+                    let Type::Object(obj) = &ty else {
+                        break;
+                    };
+                    let Some(field) = obj.get_field(&name.0) else {
+                        break;
+                    };
+                    references.push((Reference::Field(Box::new(field.clone())), name.1));
+                    ty = field.ty.clone();
+                }
+                references
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::crud::delete::DeleteStatement,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+use super::content::get_references_for_content;
+
+impl HasReferences for DeleteStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        get_references_for_content(&self.target, &None, &self.transforms, scope, variables)
+    }
+}
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::for_::ForStatement,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for ForStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![(
+            Reference::Variable {
+                declaration: self.variable.1,
+            },
+            self.variable.1,
+        )];
+        references.extend(self.iterable.get_references(scope, variables));
+        let mut variables = variables.clone();
+        variables.insert(self.variable.0.clone(), self.variable.1);
+        references.extend(
+            self.body
+                .get_references(&self.get_body_scope(scope), &variables),
+        );
+        references
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        statement::crud::{
+            insert::{InsertStatement, InsertValues},
+            output::Output,
+        },
+    },
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::references::{
+        expr::get_references_for_object,
+        has_references::{HasReferences, Reference},
+    },
+    util::span::{Span, Spanned},
+};
+
+use super::data::get_references_for_assignments;
+
+impl HasReferences for InsertStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![];
+        let mut scope = scope.clone();
+        let mut ty = Type::Any;
+        if let Some(table) = &self.table {
+            references.push((Reference::Table(table.0.clone()), table.1));
+            if let Some(obj) = scope.table_definitions.get(&table.0) {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                ty = Type::Object(obj.clone());
+            }
+        }
+        match &self.values {
+            Some((InsertValues::Content(content), _)) => {
+                references.extend(get_references_for_record(content, &ty, &scope, variables));
+            }
+            Some((InsertValues::Values { fields, rows }, _)) => {
+                for (name, span) in fields {
+                    if let Some(field) = ty.get_field(name) {
+                        references.push((Reference::Field(Box::new(field.clone())), *span));
+                    }
+                }
+                for (row, _) in rows {
+                    for value in row {
+                        references.extend(value.get_references(&scope, variables));
+                    }
+                }
+            }
+            None => {}
+        }
+        if let Some(assignments) = &self.on_duplicate {
+            references.extend(get_references_for_assignments(
+                assignments,
+                &ty,
+                &scope,
+                variables,
+            ));
+        }
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                references.extend(projection.0.expr.get_references(&scope, variables));
+            }
+        }
+        references
+    }
+}
+
+/// References in a record of type `ty`, or an array of them, being inserted.
+fn get_references_for_record(
+    record: &Spanned<Expression>,
+    ty: &Type,
+    scope: &ScopedItems,
+    variables: &HashMap<String, Span>,
+) -> Vec<Spanned<Reference>> {
+    match &record.0 {
+        Expression::Object(entries) => get_references_for_object(entries, ty, scope, variables),
+        Expression::Array(items) => items
+            .iter()
+            .flat_map(|item| get_references_for_record(item, ty, scope, variables))
+            .collect(),
+        _ => record.get_references(scope, variables),
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::let_::LetStatement,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for LetStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![];
+        if let Some(name) = &self.name {
+            references.push((
+                Reference::Variable {
+                    declaration: name.1,
+                },
+                name.1,
+            ));
+        }
+        if let Some(value) = &self.value {
+            references.extend(value.get_references(scope, variables));
+        }
+        references
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::crud::{output::Output, relate::RelateStatement},
+    declarations::{scoped_item::ScopedItems, type_::Type},
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+use super::data::get_references_for_data;
+
+impl HasReferences for RelateStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![];
+        let mut scope = scope.clone();
+        for node in [&self.from, &self.to].into_iter().flatten() {
+            references.extend(node.0.get_references(&scope, variables));
+        }
+        if let Some(edge) = &self.edge {
+            references.push((Reference::Table(edge.0.clone()), edge.1));
+        }
+        let ty = match self.get_edge_definition(&scope) {
+            Some(obj) => {
+                for field in &obj.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+                Type::Object(obj)
+            }
+            None => Type::Any,
+        };
+        if let Some(data) = &self.data {
+            references.extend(get_references_for_data(&data.0, &ty, &scope, variables));
+        }
+        if let Some((Output::Fields(projections), _)) = &self.output {
+            for projection in projections {
+                references.extend(projection.0.expr.get_references(&scope, variables));
+            }
+        }
+        references
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{expr::types::Typed, statement::crud::select::SelectStatement},
+    declarations::{field::Field, scoped_item::ScopedItems, type_::Type},
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for SelectStatement {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![];
+        let mut scope = scope.clone();
+        for from in &self.from {
+            references.extend(from.0.get_references(&scope, variables));
+            if let Some(table) = from.0.get_definition(&scope) {
+                for field in &table.fields {
+                    scope.scoped_table.fields.retain(|f| f.name != field.name);
+                    scope.scoped_table.fields.push(field.clone());
+                }
+            }
+        }
+        for projection in &self.projections {
+            let expr = &projection.0.expr;
+            references.extend(expr.get_references(&scope, variables));
+            if let Some(alias) = &projection.0.alias {
+                let ty = expr.0.get_type(&scope);
+                scope.scoped_table.fields.retain(|f| f.name != alias.0);
+                scope.scoped_table.fields.push(Field {
+                    name: alias.0.clone(),
+                    is_required: !matches!(ty, Type::Option(_)),
+                    ty,
+                    location: None,
+                    owner: None,
+                });
+            }
+        }
+        for omit in &self.omit {
+            references.extend(omit.get_references(&scope, variables));
+        }
+        for transform in &self.transforms {
+            references.extend(transform.0.get_references(&scope, variables));
+        }
+        references
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::statement::Statement,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for Spanned<Statement> {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        match &self.0 {
+            Statement::Create(create) => create.get_references(scope, variables),
+            Statement::Update(update) => update.get_references(scope, variables),
+            Statement::Upsert(upsert) => upsert.get_references(scope, variables),
+            Statement::Delete(delete) => delete.get_references(scope, variables),
+            Statement::Insert(insert) => insert.get_references(scope, variables),
+            Statement::Relate(relate) => relate.get_references(scope, variables),
+            Statement::Select(select) => select.get_references(scope, variables),
+            Statement::Define(define) => define.0.get_references(scope, variables),
+            Statement::Return(expr) => expr.get_references(scope, variables),
+            Statement::Let(let_) => let_.get_references(scope, variables),
+            Statement::IfElse(if_else) => if_else.get_references(scope, variables),
+            Statement::For(for_) => for_.get_references(scope, variables),
+            Statement::Throw(expr) | Statement::Expression(expr) => {
+                expr.get_references(scope, variables)
+            }
+            Statement::Break | Statement::Continue => vec![],
+            Statement::Invalid => vec![],
+        }
+    }
+}
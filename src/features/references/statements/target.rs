@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::target::Target,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for Target {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        let mut references = vec![];
+        if let Some(table) = self.table() {
+            references.push((Reference::Table(table.0.clone()), table.1));
+        }
+        for expr in self.expressions() {
+            references.extend(expr.get_references(scope, variables));
+        }
+        references
+    }
+}
@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::transform::Transform,
+    declarations::scoped_item::ScopedItems,
+    features::references::has_references::{HasReferences, Reference},
+    util::span::{Span, Spanned},
+};
+
+impl HasReferences for Transform {
+    fn get_references(
+        &self,
+        scope: &ScopedItems,
+        variables: &HashMap<String, Span>,
+    ) -> Vec<Spanned<Reference>> {
+        self.expressions()
+            .into_iter()
+            .flat_map(|expr| expr.get_references(scope, variables))
+            .collect()
+    }
+}
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::{RenameParams, TextEdit, WorkspaceEdit};
+
+use crate::ls::backend::Backend;
+
+use super::references::{
+    find::{find_references, get_reference_at},
+    has_references::Reference,
+};
+
+pub async fn get_rename(backend: &Backend, params: RenameParams) -> Option<WorkspaceEdit> {
+    let uri = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let rope = backend.document_map.get(uri.as_str())?.value().clone();
+    let ast = backend.ast_map.get(uri.as_str())?.value().clone();
+    let scope = backend.state.lock().await.clone();
+    let (reference, _) = get_reference_at(&ast, &scope, position, &rope)?;
+    let new_text = match reference {
+        Reference::Variable { .. } => format!("${}", params.new_name.trim_start_matches('$')),
+        _ => params.new_name,
+    };
+    let mut changes: HashMap<_, Vec<TextEdit>> = HashMap::new();
+    for location in find_references(backend, &uri, &reference, &scope) {
+        changes
+            .entry(location.uri)
+            .or_default()
+            .push(TextEdit::new(location.range, new_text.clone()));
+    }
+    Some(WorkspaceEdit::new(changes))
+}
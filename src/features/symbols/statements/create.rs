@@ -10,18 +10,8 @@ use crate::{
 impl Symbol for Spanned<&CreateStatement> {
     fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
         let mut children = Vec::new();
-        if let Some(_) = &self.0.content {
-            let content = DocumentSymbol {
-                name: "CONTENT".to_string(),
-                kind: SymbolKind::STRUCT,
-                tags: None,
-                detail: None,
-                deprecated: None,
-                range: span_to_range(&self.1, rope).unwrap(),
-                selection_range: span_to_range(&self.1, rope).unwrap(),
-                children: None,
-            };
-            children.push(content);
+        if let Some(data) = &self.0.data {
+            children.push(data.get_document_symbol(rope));
         }
         for transform in &self.0.transforms {
             children.push(transform.get_document_symbol(rope));
@@ -29,7 +19,11 @@ impl Symbol for Spanned<&CreateStatement> {
         DocumentSymbol {
             name: format!(
                 "CREATE {}",
-                self.0.table.clone().map(|x| x.0).unwrap_or("".to_string())
+                self.0
+                    .target
+                    .as_ref()
+                    .map(|x| x.0.to_string())
+                    .unwrap_or("".to_string())
             ),
             kind: SymbolKind::STRUCT,
             tags: None,
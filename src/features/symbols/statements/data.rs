@@ -0,0 +1,32 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, SymbolKind};
+
+use crate::{
+    ast::statement::crud::data::Data,
+    features::symbols::Symbol,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl Symbol for &Spanned<Data> {
+    #[allow(deprecated)]
+    fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
+        let name = match &self.0 {
+            Data::Content(_) => "CONTENT",
+            Data::Replace(_) => "REPLACE",
+            Data::Merge(_) => "MERGE",
+            Data::Patch(_) => "PATCH",
+            Data::Set(_) => "SET",
+            Data::Unset(_) => "UNSET",
+        };
+        DocumentSymbol {
+            name: name.to_string(),
+            kind: SymbolKind::STRUCT,
+            tags: None,
+            detail: None,
+            deprecated: None,
+            range: span_to_range(&self.1, rope).unwrap(),
+            selection_range: span_to_range(&self.1, rope).unwrap(),
+            children: None,
+        }
+    }
+}
@@ -16,7 +16,11 @@ impl Symbol for Spanned<&DeleteStatement> {
         DocumentSymbol {
             name: format!(
                 "DELETE {}",
-                self.0.table.clone().map(|x| x.0).unwrap_or("".to_string())
+                self.0
+                    .target
+                    .as_ref()
+                    .map(|x| x.0.to_string())
+                    .unwrap_or("".to_string())
             ),
             kind: SymbolKind::STRUCT,
             tags: None,
@@ -0,0 +1,45 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, SymbolKind};
+
+use crate::{
+    ast::statement::crud::insert::{InsertStatement, InsertValues},
+    features::symbols::Symbol,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl Symbol for Spanned<&InsertStatement> {
+    #[allow(deprecated)]
+    fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
+        let mut children = Vec::new();
+        if let Some(values) = &self.0.values {
+            let name = match values.0 {
+                InsertValues::Values { .. } => "VALUES",
+                InsertValues::Content(_) => "CONTENT",
+            };
+            children.push(DocumentSymbol {
+                name: name.to_string(),
+                kind: SymbolKind::STRUCT,
+                tags: None,
+                detail: None,
+                deprecated: None,
+                range: span_to_range(&values.1, rope).unwrap(),
+                selection_range: span_to_range(&values.1, rope).unwrap(),
+                children: None,
+            });
+        }
+        DocumentSymbol {
+            name: format!(
+                "INSERT {}{}",
+                if self.0.ignore { "IGNORE " } else { "" },
+                self.0.table.clone().map(|x| x.0).unwrap_or("".to_string())
+            ),
+            kind: SymbolKind::STRUCT,
+            tags: None,
+            detail: None,
+            deprecated: None,
+            range: span_to_range(&self.1, rope).unwrap(),
+            selection_range: span_to_range(&self.1, rope).unwrap(),
+            children: Some(children),
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod create;
+pub mod data;
 pub mod delete;
+pub mod insert;
+pub mod relate;
 pub mod select;
 pub mod statement;
 pub mod transform;
 pub mod update;
+pub mod upsert;
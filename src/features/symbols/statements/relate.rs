@@ -0,0 +1,31 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, SymbolKind};
+
+use crate::{
+    ast::statement::crud::relate::RelateStatement,
+    features::symbols::Symbol,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl Symbol for Spanned<&RelateStatement> {
+    #[allow(deprecated)]
+    fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
+        let mut children = Vec::new();
+        if let Some(data) = &self.0.data {
+            children.push(data.get_document_symbol(rope));
+        }
+        DocumentSymbol {
+            name: format!(
+                "RELATE {}",
+                self.0.edge.clone().map(|x| x.0).unwrap_or("".to_string())
+            ),
+            kind: SymbolKind::STRUCT,
+            tags: None,
+            detail: None,
+            deprecated: None,
+            range: span_to_range(&self.1, rope).unwrap(),
+            selection_range: span_to_range(&self.1, rope).unwrap(),
+            children: Some(children),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
 impl Symbol for Spanned<&SelectStatement> {
     fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
         let mut children = Vec::new();
-        if let Some(from) = &self.0.from {
+        for from in &self.0.from {
             children.push(DocumentSymbol {
                 name: format!("FROM {}", from.0),
                 kind: SymbolKind::STRUCT,
@@ -28,7 +28,12 @@ impl Symbol for Spanned<&SelectStatement> {
         DocumentSymbol {
             name: format!(
                 "SELECT {}",
-                self.0.from.clone().map(|x| x.0).unwrap_or("".to_string())
+                self.0
+                    .from
+                    .iter()
+                    .map(|x| x.0.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
             ),
             kind: SymbolKind::STRUCT,
             tags: None,
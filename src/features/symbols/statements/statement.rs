@@ -12,48 +12,42 @@ impl Symbol for &Spanned<Statement> {
         match &self.0 {
             Statement::Create(create) => (create, self.1).get_document_symbol(rope),
             Statement::Update(update) => (update, self.1).get_document_symbol(rope),
+            Statement::Upsert(upsert) => (upsert, self.1).get_document_symbol(rope),
             Statement::Delete(delete) => (delete, self.1).get_document_symbol(rope),
+            Statement::Insert(insert) => (insert, self.1).get_document_symbol(rope),
+            Statement::Relate(relate) => (relate, self.1).get_document_symbol(rope),
             Statement::Select(select) => (select, self.1).get_document_symbol(rope),
-            Statement::Return(_) => DocumentSymbol {
-                name: "return".to_string(),
-                kind: SymbolKind::FUNCTION,
-                tags: None,
-                detail: None,
-                deprecated: None,
-                range: span_to_range(&self.1, rope).unwrap(),
-                selection_range: span_to_range(&self.1, rope).unwrap(),
-                children: None,
-            },
-            Statement::Define(_) => DocumentSymbol {
-                name: "define".to_string(),
-                kind: SymbolKind::FUNCTION,
-                tags: None,
-                detail: None,
-                deprecated: None,
-                range: span_to_range(&self.1, rope).unwrap(),
-                selection_range: span_to_range(&self.1, rope).unwrap(),
-                children: None,
-            },
-            Statement::Let(_) => DocumentSymbol {
-                name: "let".to_string(),
-                kind: SymbolKind::FUNCTION,
-                tags: None,
-                detail: None,
-                deprecated: None,
-                range: span_to_range(&self.1, rope).unwrap(),
-                selection_range: span_to_range(&self.1, rope).unwrap(),
-                children: None,
-            },
-            Statement::Invalid => DocumentSymbol {
-                name: "invalid".to_string(),
-                kind: SymbolKind::NULL,
-                tags: None,
-                detail: None,
-                deprecated: None,
-                range: span_to_range(&self.1, rope).unwrap(),
-                selection_range: span_to_range(&self.1, rope).unwrap(),
-                children: None,
-            },
+            Statement::Return(_) => keyword_symbol("return", SymbolKind::FUNCTION, self, rope),
+            Statement::Define(_) => keyword_symbol("define", SymbolKind::FUNCTION, self, rope),
+            Statement::Let(_) => keyword_symbol("let", SymbolKind::FUNCTION, self, rope),
+            Statement::IfElse(_) => keyword_symbol("if", SymbolKind::OPERATOR, self, rope),
+            Statement::For(_) => keyword_symbol("for", SymbolKind::OPERATOR, self, rope),
+            Statement::Break => keyword_symbol("break", SymbolKind::EVENT, self, rope),
+            Statement::Continue => keyword_symbol("continue", SymbolKind::EVENT, self, rope),
+            Statement::Throw(_) => keyword_symbol("throw", SymbolKind::EVENT, self, rope),
+            Statement::Expression(_) => keyword_symbol("value", SymbolKind::CONSTANT, self, rope),
+            Statement::Invalid => keyword_symbol("invalid", SymbolKind::NULL, self, rope),
         }
     }
 }
+
+/// The symbol of a statement which is only named by its keyword.
+#[allow(deprecated)]
+fn keyword_symbol(
+    name: &str,
+    kind: SymbolKind,
+    statement: &Spanned<Statement>,
+    rope: &Rope,
+) -> DocumentSymbol {
+    let range = span_to_range(&statement.1, rope).unwrap();
+    DocumentSymbol {
+        name: name.to_string(),
+        kind,
+        tags: None,
+        detail: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: None,
+    }
+}
@@ -13,6 +13,14 @@ impl Symbol for &Spanned<Transform> {
             Transform::Where(_) => "where",
             Transform::Limit(_) => "limit",
             Transform::Skip(_) => "skip",
+            Transform::Split(_) => "split",
+            Transform::Group(_) => "group",
+            Transform::Order(_) => "order",
+            Transform::Fetch(_) => "fetch",
+            Transform::Timeout(_) => "timeout",
+            Transform::With => "with",
+            Transform::Parallel => "parallel",
+            Transform::Explain => "explain",
             Transform::Invalid(_) => "invalid",
         };
         DocumentSymbol {
@@ -0,0 +1,38 @@
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, SymbolKind};
+
+use crate::{
+    ast::statement::crud::upsert::UpsertStatement,
+    features::symbols::Symbol,
+    util::{range::span_to_range, span::Spanned},
+};
+
+impl Symbol for Spanned<&UpsertStatement> {
+    #[allow(deprecated)]
+    fn get_document_symbol(&self, rope: &Rope) -> DocumentSymbol {
+        let mut children = Vec::new();
+        if let Some(data) = &self.0.data {
+            children.push(data.get_document_symbol(rope));
+        }
+        for transform in &self.0.transforms {
+            children.push(transform.get_document_symbol(rope));
+        }
+        DocumentSymbol {
+            name: format!(
+                "UPSERT {}",
+                self.0
+                    .target
+                    .as_ref()
+                    .map(|x| x.0.to_string())
+                    .unwrap_or("".to_string())
+            ),
+            kind: SymbolKind::STRUCT,
+            tags: None,
+            detail: None,
+            deprecated: None,
+            range: span_to_range(&self.1, rope).unwrap(),
+            selection_range: span_to_range(&self.1, rope).unwrap(),
+            children: Some(children),
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub enum Keyword {
     Create,
     Insert,
     Update,
+    Upsert,
     Delete,
     From,
     Where,
@@ -30,6 +31,12 @@ pub enum Keyword {
     None,
     Permissions,
     Let,
+    Relate,
+    Set,
+    For,
+    Break,
+    Continue,
+    Throw,
 }
 
 impl Display for Keyword {
@@ -39,6 +46,7 @@ impl Display for Keyword {
             Keyword::Create => write!(f, "create"),
             Keyword::Insert => write!(f, "insert"),
             Keyword::Update => write!(f, "update"),
+            Keyword::Upsert => write!(f, "upsert"),
             Keyword::Delete => write!(f, "delete"),
             Keyword::From => write!(f, "from"),
             Keyword::Where => write!(f, "where"),
@@ -62,7 +70,13 @@ impl Display for Keyword {
             Keyword::Full => write!(f, "full"),
             Keyword::None => write!(f, "none"),
             Keyword::Let => write!(f, "let"),
+            Keyword::Relate => write!(f, "relate"),
+            Keyword::Set => write!(f, "set"),
             Keyword::Permissions => write!(f, "permissions"),
+            Keyword::For => write!(f, "for"),
+            Keyword::Break => write!(f, "break"),
+            Keyword::Continue => write!(f, "continue"),
+            Keyword::Throw => write!(f, "throw"),
         }
     }
 }
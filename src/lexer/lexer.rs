@@ -1,7 +1,7 @@
 use chumsky::{
     extra,
     prelude::Rich,
-    primitive::{choice, just, none_of, one_of},
+    primitive::{any, choice, end, just, none_of, one_of},
     recovery::via_parser,
     text, IterParser, Parser,
 };
@@ -10,6 +10,9 @@ use crate::util::span::Span;
 
 use super::{keyword::Keyword, token::Token};
 
+/// The largest value a SurrealDB decimal can hold.
+const MAX_DECIMAL: f64 = 7.922_816_251_426_434e28;
+
 pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Rich<'a, char>>> {
     let space = one_of(" \t").repeated().ignored().or_not();
     let ident = text::ident().map(|s: &str| {
@@ -19,6 +22,7 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
             "create" => Token::Keyword(Keyword::Create),
             "insert" => Token::Keyword(Keyword::Insert),
             "update" => Token::Keyword(Keyword::Update),
+            "upsert" => Token::Keyword(Keyword::Upsert),
             "delete" => Token::Keyword(Keyword::Delete),
             "from" => Token::Keyword(Keyword::From),
             "where" => Token::Keyword(Keyword::Where),
@@ -32,6 +36,7 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
             "type" => Token::Keyword(Keyword::Type),
             "on" => Token::Keyword(Keyword::On),
             "as" => Token::Keyword(Keyword::As),
+            "group" => Token::Keyword(Keyword::Group),
             "order" => Token::Keyword(Keyword::Order),
             "by" => Token::Keyword(Keyword::By),
             "limit" => Token::Keyword(Keyword::Limit),
@@ -42,53 +47,67 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
             "none" => Token::Keyword(Keyword::None),
             "permissions" => Token::Keyword(Keyword::Permissions),
             "let" => Token::Keyword(Keyword::Let),
+            "relate" => Token::Keyword(Keyword::Relate),
+            "set" => Token::Keyword(Keyword::Set),
+            "for" => Token::Keyword(Keyword::For),
+            "break" => Token::Keyword(Keyword::Break),
+            "continue" => Token::Keyword(Keyword::Continue),
+            "throw" => Token::Keyword(Keyword::Throw),
             "true" => Token::Boolean(true),
             "false" => Token::Boolean(false),
             _ => Token::Identifier(s.to_string()),
         }
     });
 
+    // `⟨...⟩` and `` `...` `` name identifiers and record ids which aren't
+    // plain words. The closing delimiter can be escaped with a backslash.
+    let escaped = |open: char, close: char| {
+        choice((just('\\').ignore_then(just(close)), none_of(close)))
+            .repeated()
+            .collect::<String>()
+            .delimited_by(just(open), just(close))
+    };
+    let escaped_ident = choice((escaped('⟨', '⟩'), escaped('`', '`'))).map(Token::Identifier);
+
     let variable = just('$')
         .then(text::ident())
         .map(|(_, s): (char, &str)| Token::Variable(s.to_string()));
 
     let digits = text::digits(10).to_slice();
 
-    let int = digits
-        .clone()
-        .map(|s: &str| Token::Integer(s.parse().unwrap()));
-
-    let float = digits
-        .clone()
-        .then(just('.').ignore_then(digits.clone()).or_not());
-
-    let decimal =
-        float
-            .clone()
-            .then_ignore(just("dec"))
-            .map(|(a, b): (&str, Option<&str>)| match b {
-                Some(b) => Token::Decimal(format!("{}.{}", a, b)),
-                None => Token::Decimal(a.to_string()),
-            });
-
-    let float = float.map(|(a, b): (&str, Option<&str>)| match b {
-        Some(b) => Token::Float(format!("{}.{}", a, b)),
-        None => Token::Float(a.to_string()),
-    });
-
-    let explicit_float = float.then_ignore(just('f'));
-
-    let float = digits
-        .clone()
-        .then_ignore(just('.'))
-        .then(digits.clone())
-        .map(|(a, b): (&str, &str)| Token::Float(format!("{}.{}", a, b)));
+    // `1`, `1.5`, `1e10` or `1.5e-3`, with an `f` suffix for floats or a
+    // `dec` suffix for decimals. Numbers with a fraction or an exponent are
+    // floats unless marked as decimals.
+    let exponent = one_of("eE").then(one_of("+-").or_not()).then(digits);
+    let number = digits
+        .then(just('.').then(digits).or_not())
+        .then(exponent.or_not())
+        .to_slice()
+        .then(choice((just("dec"), just("f"))).or_not())
+        .validate(|(s, suffix): (&str, Option<&str>), e, emitter| {
+            let token = match suffix {
+                Some("dec") => Token::Decimal(s.to_string()),
+                Some(_) => Token::Float(s.to_string()),
+                None if s.contains(['.', 'e', 'E']) => Token::Float(s.to_string()),
+                None => Token::Integer(s.to_string()),
+            };
+            let in_range = match &token {
+                Token::Integer(s) => s.parse::<i64>().is_ok(),
+                Token::Decimal(s) => s.parse::<f64>().is_ok_and(|n| n.abs() <= MAX_DECIMAL),
+                _ => s.parse::<f64>().is_ok_and(f64::is_finite),
+            };
+            if !in_range {
+                emitter.emit(Rich::custom(e.span(), "number is out of range"));
+            }
+            token
+        });
 
     let escape = just('\\')
         .then(choice((
             just('\\'),
             just('/'),
             just('"'),
+            just('\''),
             just('b').to('\x08'),
             just('f').to('\x0C'),
             just('n').to('\n'),
@@ -117,39 +136,49 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
         just('y').map(|_| "y"),
     ));
 
-    let duration_part = digits.clone().then(duration_unit);
+    // A duration can't run into a word, so `10dec` is a decimal and not ten
+    // days followed by `ec`.
+    let duration_part = digits.then(duration_unit);
     let duration = duration_part
-        .clone()
         .then(duration_part.repeated())
+        .then_ignore(
+            any()
+                .filter(|c: &char| c.is_alphanumeric() || *c == '_')
+                .not(),
+        )
         .to_slice()
         .map(|s: &str| Token::Duration(s.to_string()));
 
-    let string = none_of("\\\"")
-        .ignored()
-        .or(escape)
-        .repeated()
-        .to_slice()
-        .delimited_by(
-            just('"'),
-            just('"')
-                .ignored()
-                .recover_with(via_parser(one_of("\n").ignored())),
-        );
+    // Strings can be quoted with either `"` or `'`.
+    let quoted = |quote: char| {
+        none_of(['\\', quote])
+            .ignored()
+            .or(escape)
+            .repeated()
+            .to_slice()
+            .delimited_by(
+                just(quote),
+                just(quote)
+                    .ignored()
+                    .recover_with(via_parser(one_of("\n").ignored())),
+            )
+    };
+    let string = choice((quoted('"'), quoted('\'')));
 
     let record_string = just('r')
-        .ignore_then(string.clone())
+        .ignore_then(string)
         .map(|s: &str| Token::RecordString(s.to_string()));
 
     let datetime_string = just('d')
-        .ignore_then(string.clone())
+        .ignore_then(string)
         .map(|s: &str| Token::DateTime(s.to_string()));
 
     let explicit_string = just('s')
-        .ignore_then(string.clone())
+        .ignore_then(string)
         .map(|s: &str| Token::String(s.to_string()));
 
     let string = string.map(|s: &str| {
-        if let Ok(_) = chrono::DateTime::parse_from_rfc3339(s) {
+        if chrono::DateTime::parse_from_rfc3339(s).is_ok() {
             return Token::DateTime(s.to_string());
         };
         let record_id_regex = regex::Regex::new(r"^[a-zA-Z0-9_]+:[a-zA-Z0-9_]+$").unwrap();
@@ -159,7 +188,44 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
         Token::String(s.to_string())
     });
 
-    let punctuation = one_of("#_.,;:{}[]()").map(Token::Punctuation);
+    let line_comment = choice((just("--"), just("//"), just("#")))
+        .then(none_of('\n').repeated())
+        .to_slice();
+    let block_comment = just("/*")
+        .then(any().and_is(just("*/").not()).repeated())
+        .then(just("*/").ignored().recover_with(via_parser(end())))
+        .to_slice();
+    let comment =
+        choice((line_comment, block_comment)).map(|s: &str| Token::Comment(s.to_string()));
+
+    let punctuation = one_of("_.,;:{}[]()").map(Token::Punctuation);
+
+    let arrows = choice((
+        just("<->").map(|_| Token::Operator("<->".to_string())),
+        just("->").map(|_| Token::Operator("->".to_string())),
+        just("<-").map(|_| Token::Operator("<-".to_string())),
+    ));
+
+    let assignments = choice((
+        just("+=").map(|_| Token::Operator("+=".to_string())),
+        just("-=").map(|_| Token::Operator("-=".to_string())),
+    ));
+
+    // `@@` and `@1@` match full-text searches; `<|2|>` finds nearest
+    // neighbours.
+    let operators = choice((
+        just("??"),
+        just("?:"),
+        just("!~"),
+        just("~"),
+        just("<|"),
+        just("|>"),
+        just("@")
+            .then(text::digits(10).or_not())
+            .then(just("@"))
+            .to_slice(),
+    ))
+    .map(|s: &str| Token::Operator(s.to_string()));
 
     let comparisons = choice((
         just("==").map(|_| Token::Operator("==".to_string())),
@@ -171,15 +237,14 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
         just("!=").map(|_| Token::Operator("!=".to_string())),
     ));
 
-    let op = one_of("+-*/|&!")
+    let op = one_of("+-*/%|&!")
         .to_slice()
-        .then(one_of("+-*/|&!").repeated().collect::<String>())
+        .then(one_of("+-*/%|&!").repeated().collect::<String>())
         .map(|(f, s)| Token::Operator(format!("{}{}", f, s)));
 
     let new_line = one_of("\n").ignored().padded_by(space);
 
     let implicit_semi = new_line
-        .clone()
         .then_ignore(new_line.repeated())
         .map(|_| Token::Newline);
 
@@ -188,6 +253,7 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
         .padded_by(new_line.repeated());
 
     choice((
+        comment,
         semi,
         variable,
         explicit_string,
@@ -195,18 +261,156 @@ pub fn lexer<'a>() -> impl Parser<'a, &'a str, Vec<(Token, Span)>, extra::Err<Ri
         record_string,
         string,
         ident,
-        decimal,
-        explicit_float,
-        float,
+        escaped_ident,
         duration,
-        int,
+        number,
         punctuation,
+        arrows,
+        assignments,
+        operators,
         comparisons,
         op,
         implicit_semi,
     ))
-    .padded_by(space)
     .map_with(|t, e| (t, e.span()))
+    .padded_by(space)
     .repeated()
     .collect::<Vec<_>>()
 }
+
+/// The tokens the parser reads: comments are left out, and the line breaks
+/// around them merged as if they had never been written.
+pub fn remove_comments(tokens: Vec<(Token, Span)>) -> Vec<(Token, Span)> {
+    let mut result: Vec<(Token, Span)> = Vec::with_capacity(tokens.len());
+    for (token, span) in tokens {
+        match (&token, result.last()) {
+            (Token::Comment(_), _) => continue,
+            (Token::Newline, Some((Token::Newline | Token::Punctuation(';'), _))) => continue,
+            (Token::Punctuation(';'), Some((Token::Newline, _))) => {
+                result.pop();
+            }
+            _ => {}
+        }
+        result.push((token, span));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::Parser;
+
+    use super::{lexer, remove_comments, Token};
+
+    fn lex(src: &str) -> Vec<Token> {
+        lexer()
+            .parse(src)
+            .into_result()
+            .expect("the source should lex")
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    #[test]
+    fn escaped_identifiers() {
+        assert_eq!(
+            lex(r"`a b` ⟨c⟩ `d\`e` ⟨f\⟩g⟩"),
+            vec![
+                Token::Identifier("a b".to_string()),
+                Token::Identifier("c".to_string()),
+                Token::Identifier("d`e".to_string()),
+                Token::Identifier("f⟩g".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn comments() {
+        let src = "1 -- a\n# b\n// c\n/* d\ne */ 2";
+        assert_eq!(
+            lex(src),
+            vec![
+                Token::Integer("1".to_string()),
+                Token::Comment("-- a".to_string()),
+                Token::Newline,
+                Token::Comment("# b".to_string()),
+                Token::Newline,
+                Token::Comment("// c".to_string()),
+                Token::Newline,
+                Token::Comment("/* d\ne */".to_string()),
+                Token::Integer("2".to_string()),
+            ]
+        );
+        let tokens = lexer().parse(src).into_result().unwrap();
+        assert_eq!(
+            remove_comments(tokens)
+                .into_iter()
+                .map(|(token, _)| token)
+                .collect::<Vec<_>>(),
+            vec![
+                Token::Integer("1".to_string()),
+                Token::Newline,
+                Token::Integer("2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(
+            lex(r#""a" 'b' "c\"d" 'e\'f' s"person:a" r'person:a' "person:a""#),
+            vec![
+                Token::String("a".to_string()),
+                Token::String("b".to_string()),
+                Token::String(r#"c\"d"#.to_string()),
+                Token::String(r"e\'f".to_string()),
+                Token::String("person:a".to_string()),
+                Token::RecordString("person:a".to_string()),
+                Token::RecordString("person:a".to_string()),
+            ]
+        );
+        assert_eq!(
+            lex(r#"d"2024-01-01T00:00:00Z" "2024-01-01T00:00:00Z""#),
+            vec![
+                Token::DateTime("2024-01-01T00:00:00Z".to_string()),
+                Token::DateTime("2024-01-01T00:00:00Z".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn numeric_suffixes() {
+        assert_eq!(
+            lex("1 1.5 1e3 1f 1.5dec 10dec 10d"),
+            vec![
+                Token::Integer("1".to_string()),
+                Token::Float("1.5".to_string()),
+                Token::Float("1e3".to_string()),
+                Token::Float("1".to_string()),
+                Token::Decimal("1.5".to_string()),
+                Token::Decimal("10".to_string()),
+                Token::Duration("10d".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_out_of_range() {
+        for src in ["9223372036854775808", "1e400", "1e29dec"] {
+            let errors = lexer().parse(src).into_errors();
+            assert_eq!(
+                errors
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<_>>(),
+                vec!["number is out of range"],
+                "{src}"
+            );
+        }
+        assert!(lexer()
+            .parse("9223372036854775807 1e300 1e28dec")
+            .into_errors()
+            .is_empty());
+    }
+}
@@ -18,6 +18,9 @@ pub enum Token {
     Duration(String),
     RecordString(String),
     Newline,
+    /// A `-- ...`, `// ...`, `# ...` or `/* ... */` comment, including its
+    /// delimiters. Comments are removed before parsing.
+    Comment(String),
 }
 
 impl Display for Token {
@@ -37,6 +40,7 @@ impl Display for Token {
             Token::Punctuation(c) => write!(f, "{}", c),
             Token::Variable(s) => write!(f, "${}", s),
             Token::Newline => write!(f, "\\n"),
+            Token::Comment(s) => write!(f, "{}", s),
         }
     }
 }
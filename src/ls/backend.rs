@@ -5,7 +5,11 @@ use crate::ast::statement::define::DefineStatement;
 use crate::ast::statement::statement::Statement;
 use crate::declarations::scoped_item::ScopedItems;
 use crate::features::completions::completions::get_completions;
+use crate::features::definition::get_definition;
 use crate::features::diagnostics::diagnostic::parse_file;
+use crate::features::hover::get_hover;
+use crate::features::references::find::get_references;
+use crate::features::rename::get_rename;
 use crate::features::symbols::Symbol;
 use crate::ls::capabilities::get_capabilities;
 use crate::util::range::span_to_range;
@@ -17,13 +21,17 @@ use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::{
     CodeActionOrCommand, CodeActionParams, CodeActionResponse, Command, CompletionParams,
     CompletionResponse, DidChangeTextDocumentParams, DidOpenTextDocumentParams, DocumentSymbol,
-    DocumentSymbolParams, DocumentSymbolResponse, ExecuteCommandParams, InitializeParams,
-    InitializeResult, MessageType, Position, Range, SymbolKind, Url, WorkspaceEdit,
+    DocumentSymbolParams, DocumentSymbolResponse, ExecuteCommandParams, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverParams, InitializeParams, InitializeResult, Location,
+    MessageType, Position, Range, ReferenceParams, RenameParams, SymbolKind, Url, WorkspaceEdit,
 };
 use tower_lsp::{Client, LanguageServer};
 
-use super::properties::{get_table_defs, parse_config};
+use super::properties::{get_table_defs, parse_config, SchemaSource};
 use super::query::{query, send_query, update_remote_definition};
+use super::workspace::{
+    attach_locations, contains_definitions, get_workspace_files, get_workspace_table_defs,
+};
 
 pub struct Backend {
     pub client: Client,
@@ -37,23 +45,56 @@ impl Backend {
     async fn change(&self, uri: Url, text: String) {
         let filename = uri.to_string();
         let rope = Rope::from_str(&text);
+        let had_definitions = self
+            .ast_map
+            .get(filename.as_str())
+            .is_some_and(|ast| contains_definitions(ast.value()));
         let mut scope = self.state.lock().await;
         self.document_map.insert(filename.clone(), rope.clone());
         let (ast, diagnostics) = parse_file(text.clone(), &rope, &mut scope);
+        let has_definitions = ast.as_ref().is_some_and(contains_definitions);
         if let Some(ast) = ast {
             self.ast_map.insert(filename.clone(), ast);
         } else {
             self.ast_map.remove(filename.clone().as_str());
         }
+        drop(scope);
+        if self.schema_source() == SchemaSource::Workspace
+            && (had_definitions || has_definitions)
+            && self.update_definitions().await
+        {
+            self.refresh_diagnostics().await;
+            return;
+        }
         self.client
             .publish_diagnostics(uri, diagnostics, None)
             .await;
     }
 
-    pub async fn update_definitions(&self) {
-        let defs = get_table_defs(&self).await;
+    pub fn schema_source(&self) -> SchemaSource {
+        match self.properties.get("schema") {
+            Some(source) if source.value() == SchemaSource::Workspace.as_str() => {
+                SchemaSource::Workspace
+            }
+            _ => SchemaSource::Database,
+        }
+    }
+
+    /// Reloads the table definitions, returning whether they changed in a way
+    /// which affects the diagnostics of other documents.
+    pub async fn update_definitions(&self) -> bool {
+        let defs = match self.schema_source() {
+            SchemaSource::Database => {
+                let mut defs = get_table_defs(self).await;
+                attach_locations(&get_workspace_files(self), &mut defs);
+                defs
+            }
+            SchemaSource::Workspace => get_workspace_table_defs(self),
+        };
         let mut scope = self.state.lock().await;
+        let changed = scope.table_definitions != defs;
         scope.table_definitions = defs;
+        changed
     }
 
     pub async fn refresh_diagnostics(&self) {
@@ -79,8 +120,7 @@ impl LanguageServer for Backend {
             .insert("root_dir".to_string(), root_dir.clone());
         match parse_config(self).await {
             Ok(_) => {
-                let defs = get_table_defs(&self).await;
-                self.state.lock().await.table_definitions = defs;
+                self.update_definitions().await;
             }
             Err(err) => {
                 self.client
@@ -141,6 +181,25 @@ impl LanguageServer for Backend {
         Ok(Some(CompletionResponse::Array(completions)))
     }
 
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        Ok(get_definition(self, params).await)
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        Ok(get_hover(self, params).await)
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        Ok(get_references(self, params).await)
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        Ok(get_rename(self, params).await)
+    }
+
     async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
         let command = params.command;
         match command.as_str() {
@@ -160,7 +219,7 @@ impl LanguageServer for Backend {
                 for (_, span) in ast.unwrap().value() {
                     let range = span_to_range(span, &rope.value()).unwrap();
                     if range.start <= params.range.start && params.range.start <= range.end {
-                        let query = rope.slice((span.start)..(span.end)).to_string();
+                        let query = rope.byte_slice((span.start)..(span.end)).to_string();
                         let root = self.properties.get("root_dir").unwrap().clone();
                         send_query(query, &self, root).await;
                     }
@@ -175,7 +234,7 @@ impl LanguageServer for Backend {
                 for (_, span) in ast.unwrap().value() {
                     let range = span_to_range(span, &rope.value()).unwrap();
                     if range.start <= params.range.start && params.range.start <= range.end {
-                        let query_text = rope.slice((span.start)..(span.end)).to_string();
+                        let query_text = rope.byte_slice((span.start)..(span.end)).to_string();
                         query(query_text, &self).await;
                         self.client
                             .log_message(MessageType::WARNING, "Query Sent")
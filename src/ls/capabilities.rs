@@ -1,10 +1,10 @@
 use tower_lsp::lsp_types::{
     CodeActionProviderCapability, CompletionOptions, DocumentFilter, ExecuteCommandOptions,
-    InitializeResult, OneOf, SemanticTokenType, SemanticTokensFullOptions, SemanticTokensLegend,
-    SemanticTokensOptions, SemanticTokensRegistrationOptions, SemanticTokensServerCapabilities,
-    ServerCapabilities, StaticRegistrationOptions, TextDocumentRegistrationOptions,
-    TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
-    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    HoverProviderCapability, InitializeResult, OneOf, SemanticTokenType, SemanticTokensFullOptions,
+    SemanticTokensLegend, SemanticTokensOptions, SemanticTokensRegistrationOptions,
+    SemanticTokensServerCapabilities, ServerCapabilities, StaticRegistrationOptions,
+    TextDocumentRegistrationOptions, TextDocumentSyncCapability, TextDocumentSyncKind,
+    WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
 pub const LEGEND_TYPE: &[SemanticTokenType] = &[
@@ -70,6 +70,7 @@ pub fn get_capabilities() -> InitializeResult {
                 ),
             ),
             document_symbol_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
             definition_provider: Some(OneOf::Left(true)),
             references_provider: Some(OneOf::Left(true)),
             rename_provider: Some(OneOf::Left(true)),
@@ -0,0 +1,145 @@
+use dashmap::DashMap;
+use reqwest::{Body, Client, RequestBuilder};
+
+/// The settings used to reach SurrealDB's HTTP `/sql` endpoint.
+///
+/// Built from the `[connection]` values that `parse_config` stores in
+/// `Backend.properties`. Any value can be overridden with a `SURQLS_*`
+/// environment variable, and anything left unset falls back to SurrealDB's
+/// defaults.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbConnection {
+    pub host: String,
+    pub port: i32,
+    pub user: Option<String>,
+    pub pass: Option<String>,
+    pub namespace: String,
+    pub database: String,
+}
+
+impl Default for DbConnection {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 8000,
+            user: Some("root".to_string()),
+            pass: Some("root".to_string()),
+            namespace: "test".to_string(),
+            database: "test".to_string(),
+        }
+    }
+}
+
+impl DbConnection {
+    pub fn from_properties(properties: &DashMap<String, String>) -> Self {
+        Self::from_sources(properties, |name| std::env::var(name).ok())
+    }
+
+    /// Reads each setting from `env` first, then from `properties`. The
+    /// default credentials are only used when neither a user nor a password
+    /// is set, so a configured user is never sent with the default password.
+    fn from_sources(
+        properties: &DashMap<String, String>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        let get = |key: &str, name: &str| {
+            env(name).or_else(|| properties.get(key).map(|value| value.value().clone()))
+        };
+        let default = DbConnection::default();
+        let (user, pass) = match (get("user", "SURQLS_USER"), get("pass", "SURQLS_PASS")) {
+            (None, None) => (default.user, default.pass),
+            credentials => credentials,
+        };
+        DbConnection {
+            host: get("host", "SURQLS_HOST").unwrap_or(default.host),
+            port: get("port", "SURQLS_PORT")
+                .and_then(|port| port.parse().ok())
+                .unwrap_or(default.port),
+            user,
+            pass,
+            namespace: get("namespace", "SURQLS_NS").unwrap_or(default.namespace),
+            database: get("database", "SURQLS_DB").unwrap_or(default.database),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        if self.host.contains("://") {
+            format!("{}:{}/sql", self.host, self.port)
+        } else {
+            format!("http://{}:{}/sql", self.host, self.port)
+        }
+    }
+
+    pub fn request(&self, client: &Client, body: impl Into<Body>) -> RequestBuilder {
+        let request = client
+            .post(self.url())
+            .body(body)
+            .header("NS", &self.namespace)
+            .header("DB", &self.database)
+            .header("Accept", "application/json");
+        match &self.user {
+            Some(user) => request.basic_auth(user, self.pass.as_ref()),
+            None => request,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dashmap::DashMap;
+
+    use super::DbConnection;
+
+    fn connection(properties: &[(&str, &str)], env: &[(&str, &str)]) -> DbConnection {
+        let properties = properties
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<DashMap<_, _>>();
+        DbConnection::from_sources(&properties, |name| {
+            env.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn environment_variables_override_the_config() {
+        let connection = connection(
+            &[("host", "db.local"), ("port", "9000"), ("namespace", "app")],
+            &[("SURQLS_PORT", "8080"), ("SURQLS_DB", "main")],
+        );
+        assert_eq!(
+            connection,
+            DbConnection {
+                host: "db.local".to_string(),
+                port: 8080,
+                namespace: "app".to_string(),
+                database: "main".to_string(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(connection.url(), "http://db.local:8080/sql");
+    }
+
+    #[test]
+    fn default_credentials_are_only_used_when_none_are_set() {
+        let credentials = |properties: &[(&str, &str)], env: &[(&str, &str)]| {
+            let connection = connection(properties, env);
+            (connection.user, connection.pass)
+        };
+        let some = |value: &str| Some(value.to_string());
+        assert_eq!(credentials(&[], &[]), (some("root"), some("root")));
+        assert_eq!(
+            credentials(&[("user", "admin")], &[]),
+            (some("admin"), None)
+        );
+        assert_eq!(
+            credentials(&[("user", "admin")], &[("SURQLS_PASS", "secret")]),
+            (some("admin"), some("secret"))
+        );
+        assert_eq!(
+            credentials(&[], &[("SURQLS_PASS", "secret")]),
+            (None, some("secret"))
+        );
+    }
+}
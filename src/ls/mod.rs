@@ -1,5 +1,7 @@
 pub mod backend;
 pub mod capabilities;
+pub mod connection;
 pub mod properties;
 pub mod query;
 pub mod server;
+pub mod workspace;
@@ -11,13 +11,45 @@ use crate::declarations::field::Field;
 use crate::declarations::object::Object;
 use crate::declarations::scoped_item::ScopedItems;
 use crate::declarations::type_::Type;
-use crate::{lexer::lexer::lexer, parser::parser::parser};
+use crate::{
+    lexer::lexer::{lexer, remove_comments},
+    parser::parser::parser,
+};
 
 use super::backend::Backend;
+use super::connection::DbConnection;
 
 #[derive(Deserialize)]
 pub struct Properties {
-    pub connection: Connection,
+    pub connection: Option<Connection>,
+    #[serde(default)]
+    pub schema: Schema,
+}
+
+#[derive(Deserialize, Default)]
+pub struct Schema {
+    #[serde(default)]
+    pub source: SchemaSource,
+}
+
+/// Where table definitions are read from.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaSource {
+    /// `INFO FOR DATABASE` on the configured connection.
+    #[default]
+    Database,
+    /// `DEFINE TABLE`/`DEFINE FIELD` statements in the workspace's `.surql` files.
+    Workspace,
+}
+
+impl SchemaSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SchemaSource::Database => "database",
+            SchemaSource::Workspace => "workspace",
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -26,6 +58,8 @@ pub struct Connection {
     pub port: i32,
     pub user: Option<String>,
     pub pass: Option<String>,
+    pub namespace: Option<String>,
+    pub database: Option<String>,
 }
 
 pub async fn parse_config(backend: &Backend) -> Result<(), ()> {
@@ -51,21 +85,37 @@ pub async fn parse_config(backend: &Backend) -> Result<(), ()> {
     let properties = toml::from_str::<Properties>(&toml_str);
     match properties {
         Ok(properties) => {
-            backend
-                .properties
-                .insert("host".to_string(), properties.connection.host);
-            backend
-                .properties
-                .insert("port".to_string(), properties.connection.port.to_string());
-            if let Some(user) = properties.connection.user {
+            backend.properties.insert(
+                "schema".to_string(),
+                properties.schema.source.as_str().to_string(),
+            );
+            if let Some(connection) = properties.connection {
                 backend
                     .properties
-                    .insert("user".to_string(), user.to_string());
-            }
-            if let Some(pass) = properties.connection.pass {
+                    .insert("host".to_string(), connection.host);
                 backend
                     .properties
-                    .insert("pass".to_string(), pass.to_string());
+                    .insert("port".to_string(), connection.port.to_string());
+                if let Some(user) = connection.user {
+                    backend
+                        .properties
+                        .insert("user".to_string(), user.to_string());
+                }
+                if let Some(pass) = connection.pass {
+                    backend
+                        .properties
+                        .insert("pass".to_string(), pass.to_string());
+                }
+                if let Some(namespace) = connection.namespace {
+                    backend
+                        .properties
+                        .insert("namespace".to_string(), namespace.to_string());
+                }
+                if let Some(database) = connection.database {
+                    backend
+                        .properties
+                        .insert("database".to_string(), database.to_string());
+                }
             }
             Ok(())
         }
@@ -81,13 +131,9 @@ pub async fn parse_config(backend: &Backend) -> Result<(), ()> {
 
 pub async fn get_table_defs(backend: &Backend) -> HashMap<String, Object> {
     let client = reqwest::Client::new();
-    let res = client
-        .post("http://localhost:8000/sql")
-        .body("INFO FOR DATABASE;")
-        .header("NS", "test")
-        .header("DB", "test")
-        .header("Accept", "application/json")
-        .basic_auth("root", Some("root"))
+    let connection = DbConnection::from_properties(&backend.properties);
+    let res = connection
+        .request(&client, "INFO FOR DATABASE;")
         .send()
         .await;
     let mut table_defs = HashMap::new();
@@ -103,13 +149,8 @@ pub async fn get_table_defs(backend: &Backend) -> HashMap<String, Object> {
                 .tables;
             for (name, _) in tables {
                 let query = format!("INFO FOR TABLE {};", &name);
-                let text = client
-                    .post("http://localhost:8000/sql")
-                    .body(query.clone())
-                    .header("NS", "test")
-                    .header("DB", "test")
-                    .header("Accept", "application/json")
-                    .basic_auth("root", Some("root"))
+                let text = connection
+                    .request(&client, query.clone())
                     .send()
                     .await
                     .unwrap()
@@ -128,7 +169,7 @@ pub async fn get_table_defs(backend: &Backend) -> HashMap<String, Object> {
                     .collect::<Vec<_>>()
                     .join(";\n");
 
-                let tokens = lexer().parse(&text).into_output().unwrap();
+                let tokens = remove_comments(lexer().parse(&text).into_output().unwrap());
                 let parser_result = parser()
                     .parse_with_state(
                         tokens.as_slice().spanned((text.len()..text.len()).into()),
@@ -143,12 +184,7 @@ pub async fn get_table_defs(backend: &Backend) -> HashMap<String, Object> {
                         _ => panic!("Expected define statement"),
                     })
                     .collect::<Vec<_>>();
-                let mut type_ = parse_table_defs(&statements, "".to_string(), backend).await;
-                type_.fields.push(Field {
-                    name: "id".to_string(),
-                    ty: Type::Record(name.clone()),
-                    is_required: false,
-                });
+                let type_ = parse_table_def(&name, &statements);
                 table_defs.insert(name, type_);
             }
 
@@ -186,20 +222,41 @@ pub struct TableInfo {
 type InfoResult = Vec<SurrealResponse<DatabaseInfo>>;
 type TableResult = Vec<SurrealResponse<TableInfo>>;
 
-#[async_recursion::async_recursion]
-pub async fn parse_table_defs(
-    statements: &Vec<DefineStatement>,
-    parents: String,
-    backend: &Backend,
-) -> Object {
-    backend
-        .client
-        .log_message(
-            MessageType::ERROR,
-            format!("parsing table defs parents: {}", parents),
-        )
-        .await;
-    let mut fields = Vec::new();
+/// Builds the definition of `table` from the `DEFINE FIELD` statements on it,
+/// adding the implicit `id` field.
+pub fn parse_table_def(table: &str, statements: &Vec<DefineStatement>) -> Object {
+    let mut type_ = parse_table_defs(statements, "".to_string());
+    type_.fields.retain(|f| f.name != "id");
+    type_.fields.push(Field {
+        name: "id".to_string(),
+        ty: Type::Record(table.to_string()),
+        is_required: false,
+        location: None,
+        owner: None,
+    });
+    set_owner(&mut type_, table, &[]);
+    type_
+}
+
+/// Marks the fields of `obj`, and the fields nested in them, as defined on
+/// `table` inside the fields `parents`.
+fn set_owner(obj: &mut Object, table: &str, parents: &[String]) {
+    for field in &mut obj.fields {
+        field.owner = Some((table.to_string(), parents.to_vec()));
+        let mut ty = &mut field.ty;
+        while let Type::Array(inner) | Type::Option(inner) = ty {
+            ty = inner;
+        }
+        if let Type::Object(inner) = ty {
+            let mut path = parents.to_vec();
+            path.push(field.name.clone());
+            set_owner(inner, table, &path);
+        }
+    }
+}
+
+pub fn parse_table_defs(statements: &Vec<DefineStatement>, parents: String) -> Object {
+    let mut fields: Vec<Field> = Vec::new();
     for statement in statements {
         match statement {
             DefineStatement::Field((field, _)) => {
@@ -211,19 +268,33 @@ pub async fn parse_table_defs(
                     .collect::<Vec<_>>()
                     .join(".");
                 if parent == parents {
-                    let field_type = &field.type_.clone().unwrap();
+                    fields.retain(|f| f.name != field.name.0);
+                    let field_type = match &field.type_ {
+                        Some(type_) => type_,
+                        None => {
+                            fields.push(Field {
+                                name: field.name.0.clone(),
+                                ty: Type::Any,
+                                is_required: false,
+                                location: None,
+                                owner: None,
+                            });
+                            continue;
+                        }
+                    };
                     if &field_type.0.name.0 == "object" {
                         let new_parent = if parents == "" {
                             field.name.0.clone()
                         } else {
                             format!("{}.{}", parents, field.name.0)
                         };
-                        let ty =
-                            Type::Object(parse_table_defs(statements, new_parent, backend).await);
+                        let ty = Type::Object(parse_table_defs(statements, new_parent));
                         fields.push(Field {
                             name: field.name.0.clone(),
                             ty,
                             is_required: false,
+                            location: None,
+                            owner: None,
                         });
                     } else {
                         let ty = parse_declared_type(&field_type.0);
@@ -234,6 +305,8 @@ pub async fn parse_table_defs(
                                 _ => true,
                             },
                             ty,
+                            location: None,
+                            owner: None,
                         });
                     }
                 }
@@ -241,7 +314,11 @@ pub async fn parse_table_defs(
             DefineStatement::Table(_) => continue,
         }
     }
-    Object { fields }
+    Object {
+        fields,
+        location: None,
+        is_flexible: false,
+    }
 }
 
 pub fn parse_declared_type(AstType { name, args }: &AstType) -> Type {
@@ -249,12 +326,13 @@ pub fn parse_declared_type(AstType { name, args }: &AstType) -> Type {
         "string" => Type::String,
         "int" => Type::Int,
         "float" => Type::Float,
-        "boolean" => Type::Bool,
+        "bool" | "boolean" => Type::Bool,
         "decimal" => Type::Decimal,
         "number" => Type::Number,
         "null" => Type::Null,
         "any" => Type::Any,
         "datetime" => Type::DateTime,
+        "duration" => Type::Duration,
         "array" => {
             if args.len() != 1 {
                 Type::Error
@@ -275,9 +353,13 @@ pub fn parse_declared_type(AstType { name, args }: &AstType) -> Type {
                             _ => true,
                         },
                         ty,
+                        location: None,
+                        owner: None,
                     }
                 })
                 .collect::<Vec<_>>(),
+            location: None,
+            is_flexible: args.is_empty(),
         }),
         "option" => {
             if args.len() != 1 {
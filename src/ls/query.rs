@@ -1,17 +1,12 @@
 use serde_json::Value;
 use tower_lsp::lsp_types::{MessageType, ShowDocumentParams};
 
-use super::{backend::Backend, properties::SurrealResponse};
+use super::{backend::Backend, connection::DbConnection, properties::SurrealResponse};
 
 pub async fn send_query(query: String, backend: &Backend, root: String) {
     let client = reqwest::Client::new();
-    let res = client
-        .post("http://localhost:8000/sql")
-        .body(query)
-        .header("NS", "test")
-        .header("DB", "test")
-        .header("Accept", "application/json")
-        .basic_auth("root", Some("root"))
+    let res = DbConnection::from_properties(&backend.properties)
+        .request(&client, query)
         .send()
         .await;
     match res {
@@ -54,13 +49,8 @@ pub async fn send_query(query: String, backend: &Backend, root: String) {
 
 pub async fn query(query: String, backend: &Backend) -> Option<Value> {
     let client = reqwest::Client::new();
-    let res = client
-        .post("http://localhost:8000/sql")
-        .body(query)
-        .header("NS", "test")
-        .header("DB", "test")
-        .header("Accept", "application/json")
-        .basic_auth("root", Some("root"))
+    let res = DbConnection::from_properties(&backend.properties)
+        .request(&client, query)
         .send()
         .await;
     match res {
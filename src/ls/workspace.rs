@@ -0,0 +1,231 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use chumsky::{input::Input, Parser};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Location, Url};
+
+use crate::{
+    ast::{
+        parser::File,
+        statement::{define::DefineStatement, statement::Statement},
+    },
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    lexer::lexer::{lexer, remove_comments},
+    parser::parser::parser,
+    util::range::span_to_range,
+};
+
+use super::{backend::Backend, properties::parse_table_def};
+
+/// Every `.surql` document in the workspace which parses, with its syntax
+/// tree. Open documents are taken from the editor rather than the disk so
+/// unsaved changes are taken into account, and aren't parsed again.
+pub fn get_workspace_files(backend: &Backend) -> Vec<(Url, Rope, File)> {
+    let mut paths = vec![];
+    if let Some(root) = backend
+        .properties
+        .get("root_dir")
+        .and_then(|root| Url::parse(root.value()).ok())
+        .and_then(|root| root.to_file_path().ok())
+    {
+        find_surql_files(&root, &mut paths);
+    }
+    let mut files = vec![];
+    for path in paths {
+        let Ok(uri) = Url::from_file_path(&path) else {
+            continue;
+        };
+        if backend.document_map.contains_key(uri.as_str()) {
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let rope = Rope::from_str(&text);
+        if let Some(file) = parse_document(&rope) {
+            files.push((uri, rope, file));
+        }
+    }
+    for document in backend.document_map.iter() {
+        let Ok(uri) = Url::parse(document.key()) else {
+            continue;
+        };
+        let rope = document.value().clone();
+        let file = match backend.ast_map.get(document.key()) {
+            Some(ast) => Some(ast.value().clone()),
+            None => parse_document(&rope),
+        };
+        if let Some(file) = file {
+            files.push((uri, rope, file));
+        }
+    }
+    files
+}
+
+fn find_surql_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" && name != "node_modules" {
+                find_surql_files(&path, paths);
+            }
+        } else if name.ends_with(".surql") {
+            paths.push(path);
+        }
+    }
+}
+
+pub fn parse_document(rope: &Rope) -> Option<File> {
+    let text = rope.to_string();
+    let tokens = remove_comments(lexer().parse(text.as_str()).into_output()?);
+    let file = parser()
+        .parse_with_state(
+            tokens
+                .as_slice()
+                .spanned((rope.len_bytes()..rope.len_bytes()).into()),
+            &mut ScopedItems::default(),
+        )
+        .into_output();
+    file
+}
+
+pub fn contains_definitions(file: &File) -> bool {
+    file.iter()
+        .any(|statement| matches!(statement.0, Statement::Define(_)))
+}
+
+/// Builds the table definitions from the `DEFINE TABLE` and `DEFINE FIELD`
+/// statements found in the workspace, without connecting to a database.
+/// Each document is only parsed once for both the definitions and where they
+/// are declared.
+pub fn get_workspace_table_defs(backend: &Backend) -> HashMap<String, Object> {
+    let files = get_workspace_files(backend);
+    let mut table_defs = get_table_defs(files.iter().map(|(_, _, file)| file.clone()));
+    attach_locations(&files, &mut table_defs);
+    table_defs
+}
+
+/// Builds the table definitions from the `DEFINE TABLE` and `DEFINE FIELD`
+/// statements in `files`.
+pub fn get_table_defs(files: impl IntoIterator<Item = File>) -> HashMap<String, Object> {
+    let mut tables: HashMap<String, Vec<DefineStatement>> = HashMap::new();
+    for file in files {
+        for (statement, _) in file {
+            let Statement::Define((define, _)) = statement else {
+                continue;
+            };
+            match &define {
+                DefineStatement::Table((table, _)) => {
+                    tables.entry(table.name.0.clone()).or_default();
+                }
+                DefineStatement::Field((field, _)) => {
+                    if let Some(table) = &field.table_name {
+                        tables.entry(table.0.clone()).or_default().push(define);
+                    }
+                }
+            }
+        }
+    }
+    tables
+        .into_iter()
+        .map(|(name, statements)| {
+            let table = parse_table_def(&name, &statements);
+            (name, table)
+        })
+        .collect()
+}
+
+/// Records where each table and field in `table_defs` is declared by the
+/// `DEFINE TABLE` and `DEFINE FIELD` statements in `files`.
+pub fn attach_locations(files: &[(Url, Rope, File)], table_defs: &mut HashMap<String, Object>) {
+    for (uri, rope, file) in files {
+        for (statement, _) in file {
+            let Statement::Define((define, _)) = statement else {
+                continue;
+            };
+            match define {
+                DefineStatement::Table((table, _)) => {
+                    if let Some(obj) = table_defs.get_mut(&table.name.0) {
+                        obj.location = span_to_range(&table.name.1, rope)
+                            .map(|range| Location::new(uri.clone(), range));
+                    }
+                }
+                DefineStatement::Field((field, _)) => {
+                    let Some(table) = &field.table_name else {
+                        continue;
+                    };
+                    let Some(obj) = table_defs.get_mut(&table.0) else {
+                        continue;
+                    };
+                    let path = field
+                        .parents
+                        .iter()
+                        .chain([&field.name])
+                        .map(|(name, _)| name.as_str())
+                        .collect::<Vec<_>>();
+                    if let Some(def) = find_field_mut(obj, &path) {
+                        def.location = span_to_range(&field.name.1, rope)
+                            .map(|range| Location::new(uri.clone(), range));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn find_field_mut<'a>(obj: &'a mut Object, path: &[&str]) -> Option<&'a mut Field> {
+    let (name, rest) = path.split_first()?;
+    let field = obj.fields.iter_mut().find(|f| f.name == *name)?;
+    if rest.is_empty() {
+        return Some(field);
+    }
+    let mut ty = &mut field.ty;
+    while let Type::Array(inner) | Type::Option(inner) = ty {
+        ty = inner.as_mut();
+    }
+    match ty {
+        Type::Object(inner) => find_field_mut(inner, rest),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+    use tower_lsp::lsp_types::{Location, Position, Range, Url};
+
+    use crate::util::testing::{parse, SCHEMA};
+
+    use super::{attach_locations, get_table_defs};
+
+    #[test]
+    fn definitions_are_located_from_the_same_parse() {
+        let uri = Url::parse("file:///schema.surql").unwrap();
+        let files = [(uri.clone(), Rope::from_str(SCHEMA), parse(SCHEMA))];
+        let mut table_defs = get_table_defs(files.iter().map(|(_, _, file)| file.clone()));
+        attach_locations(&files, &mut table_defs);
+        let range =
+            |line, start, end| Range::new(Position::new(line, start), Position::new(line, end));
+        let person = &table_defs["person"];
+        assert_eq!(
+            person
+                .location
+                .as_ref()
+                .map(|location| (&location.uri, location.range)),
+            Some((&uri, range(1, 13, 19)))
+        );
+        assert_eq!(
+            person
+                .get_field("age")
+                .and_then(|field| field.location.clone()),
+            Some(Location::new(uri, range(3, 13, 16)))
+        );
+    }
+}
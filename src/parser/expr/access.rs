@@ -12,7 +12,7 @@ use crate::{
     util::span::{ParserInput, Spanned},
 };
 
-use super::newline::optional_new_line;
+use super::{graph::graph_parser, newline::optional_new_line};
 
 pub fn access_parser<'tokens, 'src: 'tokens>(
     atom: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
@@ -32,36 +32,44 @@ pub fn access_parser<'tokens, 'src: 'tokens>(
                 .padded_by(optional_new_line()),
             optional_new_line().then_ignore(just(Token::Punctuation(']'))),
         )
-        .map(Access::Index)
+        .map_with(|index, s| (Access::Index(index), s.span()))
         .boxed();
 
     let field = just(Token::Punctuation('.'))
         .padded_by(optional_new_line())
-        .ignore_then(select! {
-            Token::Identifier(s) => s,
-        })
+        .ignore_then(
+            choice((
+                select! {
+                    Token::Identifier(s) => Access::Property(s.to_string()),
+                },
+                just(Token::Operator("*".to_string())).map(|_| Access::All),
+            ))
+            .map_with(|access, e| (access, e.span())),
+        )
         .labelled("field")
-        .map(|s| Access::Property(s.to_string()))
+        .boxed();
+
+    let graph = graph_parser(expr)
+        .map(|graph| {
+            let span = graph.table.1;
+            (Access::Graph(graph), span)
+        })
         .boxed();
 
     let access = atom
         .clone()
-        .foldl_with(choice((index, field)).repeated(), |a, acc, s| match acc {
-            Access::Index(index) => (
-                Expression::Access {
-                    expr: Box::new(a),
-                    access: (Box::new(Access::Index(index)), s.span()),
-                },
-                s.span(),
-            ),
-            Access::Property(field) => (
-                Expression::Access {
-                    expr: Box::new(a),
-                    access: (Box::new(Access::Property(field)), s.span()),
-                },
-                s.span(),
-            ),
-        })
+        .foldl_with(
+            choice((index, field, graph)).repeated(),
+            |a, (acc, span), s| {
+                (
+                    Expression::Access {
+                        expr: Box::new(a),
+                        access: (Box::new(acc), span),
+                    },
+                    s.span(),
+                )
+            },
+        )
         .boxed();
 
     access
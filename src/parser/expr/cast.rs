@@ -0,0 +1,91 @@
+use chumsky::{primitive::just, Parser};
+
+use crate::{
+    ast::{expr::parser::Expression, parser::Extra},
+    lexer::token::Token,
+    parser::type_::type_parser,
+    util::span::{ParserInput, Spanned},
+};
+
+pub fn cast_parser<'tokens, 'src: 'tokens>(
+    expr: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    type_parser()
+        .delimited_by(
+            just(Token::Operator("<".to_string())),
+            just(Token::Operator(">".to_string())),
+        )
+        .then(expr)
+        .map_with(|(ty, expr), s| {
+            (
+                Expression::Cast {
+                    ty,
+                    expr: Box::new(expr),
+                },
+                s.span(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{get_diagnostics, get_located_diagnostics, get_type, scope, SCHEMA},
+    };
+
+    #[test]
+    fn casts_are_typed_by_their_target() {
+        let scope = Default::default();
+        assert_eq!(get_type("RETURN <int> '1'", &scope), Type::Int);
+        assert_eq!(
+            get_type("RETURN <array<string>> [1, 2]", &scope),
+            Type::Array(Box::new(Type::String))
+        );
+        assert_eq!(get_type("RETURN <future> { 1 + 1 }", &scope), Type::Int);
+        assert_eq!(
+            get_type("RETURN <future> { time::now() }", &scope),
+            Type::DateTime
+        );
+    }
+
+    #[test]
+    fn impossible_casts_are_reported() {
+        let scope = Default::default();
+        assert_eq!(
+            get_diagnostics("RETURN <bool> [1]", &scope),
+            vec!["Cannot cast array<int> to bool"]
+        );
+        assert!(get_diagnostics("RETURN <int> '1'", &scope).is_empty());
+        assert!(get_diagnostics("RETURN <future> { 1 + 1 }", &scope).is_empty());
+    }
+
+    #[test]
+    fn casts_and_unary_operators_nest() {
+        let scope = Default::default();
+        assert_eq!(get_type("RETURN <int> <string> 1", &scope), Type::Int);
+        assert_eq!(get_type("RETURN <int> -1", &scope), Type::Int);
+        assert_eq!(get_type("RETURN -<int> '1'", &scope), Type::Int);
+        assert_eq!(get_type("RETURN !<bool> <string> 1", &scope), Type::Bool);
+        assert!(get_diagnostics("RETURN <int> <string> 1", &scope).is_empty());
+    }
+
+    #[test]
+    fn unknown_targets_are_reported() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_located_diagnostics("RETURN <nope> 1", &scope),
+            vec![("nope".to_string(), "Type 'nope' not found".to_string())]
+        );
+        assert_eq!(
+            get_located_diagnostics("RETURN <option<record<nope>>> 1", &scope),
+            vec![("nope".to_string(), "Table 'nope' not found".to_string())]
+        );
+        assert!(get_diagnostics("RETURN <record<person>> 'person:a'", &scope).is_empty());
+        assert!(get_diagnostics("RETURN <uuid> 'a'", &scope).is_empty());
+    }
+}
@@ -11,19 +11,29 @@ use crate::{
 
 use super::newline::optional_new_line;
 
+/// Besides statements, a block can hold bare values, so that
+/// `{ LET $a = 1; $a + 1 }` returns `$a + 1`.
 pub fn code_block_parser<'tokens, 'src: 'tokens>(
+    expr: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
     stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
         + Clone
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
        + Clone
        + 'tokens {
-    stmt
-        // .recover_with(via_parser(invalid_statement_parser()))
-        .separated_by(choice((
-            just(Token::Newline),
-            just(Token::Punctuation(';')),
-        )))
+    let separator = choice((just(Token::Newline), just(Token::Punctuation(';'))));
+
+    // Only take the value if it ends the statement, otherwise leave it to the
+    // statement parser and its recovery.
+    let value = expr
+        .then_ignore(choice((separator.clone(), just(Token::Punctuation('}')))).rewind())
+        .map(Statement::Expression)
+        .map_with(|s, span| (s, span.span()));
+
+    choice((value, stmt))
+        .separated_by(separator)
         .allow_trailing()
         .collect::<Vec<_>>()
         .delimited_by(
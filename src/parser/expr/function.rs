@@ -19,15 +19,21 @@ pub fn function_parser<'tokens, 'src: 'tokens>(
         Token::Identifier(s) => s,
     }
     .map_with(|s, span| (s, span.span()));
+    // Namespaced names such as `type::int` or `http::delete` can contain keywords.
+    let segment = select! {
+        Token::Identifier(s) => s,
+        Token::Keyword(kw) => kw.to_string(),
+    }
+    .map_with(|s, span| (s, span.span()));
     let function_sep = just(Token::Punctuation(':')).then(just(Token::Punctuation(':')));
-    let part = ident.clone().then_ignore(function_sep);
+    let part = segment.clone().then_ignore(function_sep);
 
     let name = part
         .clone()
         .then(
             part.repeated()
                 .collect::<Vec<_>>()
-                .then(ident.or_not())
+                .then(segment.or_not())
                 .map(|(mut v, last)| {
                     if let Some(last) = last {
                         v.push(last);
@@ -56,3 +62,70 @@ pub fn function_parser<'tokens, 'src: 'tokens>(
             .then(args.clone().map(|args| Some(args))))
         .map_with(|(name, args), s| (Expression::Call { name, args }, s.span()))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_diagnostics, get_located_diagnostics, get_type, option},
+    };
+
+    #[test]
+    fn generic_functions_are_typed_by_their_arguments() {
+        let scope = Default::default();
+        assert_eq!(
+            get_type("RETURN array::first([1, 2])", &scope),
+            option(Type::Int)
+        );
+        assert_eq!(
+            get_type("RETURN array::last(['a'])", &scope),
+            option(Type::String)
+        );
+        assert_eq!(
+            get_type("RETURN array::add([1], 2)", &scope),
+            array(Type::Int)
+        );
+    }
+
+    #[test]
+    fn unmatched_overloads_are_reported_on_the_argument() {
+        let scope = Default::default();
+        let diagnostics = get_located_diagnostics("RETURN rand::int(1, 'a')", &scope);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, "'a'");
+        assert!(diagnostics[0]
+            .1
+            .starts_with("No overload of function 'rand::int'"));
+        let diagnostics = get_located_diagnostics("RETURN rand::int(1, 2, 3)", &scope);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, "rand::int(1, 2, 3)");
+    }
+
+    #[test]
+    fn object_parameters_take_objects_of_any_shape() {
+        let scope = Default::default();
+        for src in [
+            "RETURN object::keys({ a: 1 })",
+            "RETURN object::len({ a: 1, b: { c: true } })",
+            "RETURN http::get('https://surrealdb.com', { accept: 'text/html' })",
+            "LET $o = object::from_entries([['a', 1]]); RETURN $o.a",
+        ] {
+            assert!(get_diagnostics(src, &scope).is_empty(), "{src}");
+        }
+        assert_eq!(
+            get_type("RETURN object::keys({ a: 1 })", &scope),
+            array(Type::String)
+        );
+        assert_eq!(get_diagnostics("RETURN object::keys(1)", &scope).len(), 1);
+    }
+
+    #[test]
+    fn values_of_unknown_type_fit_any_parameter() {
+        let scope = Default::default();
+        let src = "LET $x = http::get('https://surrealdb.com'); RETURN string::len($x)";
+        assert!(get_diagnostics(src, &scope).is_empty());
+        assert_eq!(get_type(src, &scope), Type::Int);
+        let src = "LET $r = type::thing('person', 1); RETURN string::len(record::id($r))";
+        assert!(get_diagnostics(src, &scope).is_empty());
+    }
+}
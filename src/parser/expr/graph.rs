@@ -0,0 +1,66 @@
+use chumsky::{primitive::just, select, Parser};
+
+use crate::{
+    ast::{
+        expr::{
+            access::{Direction, Graph},
+            parser::Expression,
+        },
+        parser::Extra,
+    },
+    lexer::{keyword::Keyword, token::Token},
+    util::span::{ParserInput, Spanned},
+};
+
+use super::newline::optional_new_line;
+
+pub fn graph_parser<'tokens, 'src: 'tokens>(
+    expr: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Graph, Extra<'tokens>> + Clone + 'tokens {
+    let direction = select! {
+        Token::Operator(op) if op == "->" => Direction::Out,
+        Token::Operator(op) if op == "<-" => Direction::In,
+        Token::Operator(op) if op == "<->" => Direction::Both,
+    };
+    let table = select! {
+        Token::Identifier(s) => s.to_string(),
+    }
+    .map_with(|s, e| (s, e.span()));
+    let condition = just(Token::Keyword(Keyword::Where))
+        .padded_by(optional_new_line())
+        .ignore_then(expr)
+        .delimited_by(
+            just(Token::Punctuation('[')),
+            optional_new_line().then(just(Token::Punctuation(']'))),
+        );
+
+    direction
+        .then(table)
+        .then(condition.or_not())
+        .map(|((direction, table), condition)| Graph {
+            direction,
+            table,
+            condition,
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::testing::{get_diagnostics, scope, SCHEMA};
+
+    #[test]
+    fn traversals_are_only_expected_where_they_can_start() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_diagnostics("CREATE person SET name = 'a' foo", &scope),
+            vec!["found 'foo' expected something else"]
+        );
+        assert_eq!(
+            get_diagnostics("RETURN [1, ;", &scope)[0],
+            "found ';' expected graph traversal"
+        );
+    }
+}
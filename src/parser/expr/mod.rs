@@ -1,7 +1,9 @@
 pub mod access;
 pub mod array;
+pub mod cast;
 pub mod code_block;
 pub mod function;
+pub mod graph;
 pub mod literal;
 pub mod newline;
 pub mod object;
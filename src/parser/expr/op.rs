@@ -1,6 +1,7 @@
 use chumsky::{
     primitive::{choice, just},
-    Parser,
+    recursive::recursive,
+    select, IterParser, Parser,
 };
 
 use crate::{
@@ -8,11 +9,12 @@ use crate::{
         expr::{op::BinaryOperator, parser::Expression, unary::UnaryOperator},
         parser::Extra,
     },
-    lexer::token::Token,
+    lexer::{keyword::Keyword, token::Token},
+    parser::keyword::word,
     util::span::{ParserInput, Spanned},
 };
 
-use super::newline::optional_new_line;
+use super::{cast::cast_parser, newline::optional_new_line};
 
 pub fn op_parser<'tokens, 'src: 'tokens>(
     atom: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
@@ -21,132 +23,179 @@ pub fn op_parser<'tokens, 'src: 'tokens>(
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
        + Clone
        + 'tokens {
+    let op = |s: &str| just(Token::Operator(s.to_string()));
+
     let unary_op = choice((
-        just(Token::Operator("-".to_string())).map(|_| UnaryOperator::Negate),
-        just(Token::Operator("!".to_string())).map(|_| UnaryOperator::Not),
+        op("-").map(|_| UnaryOperator::Negate),
+        op("!").map(|_| UnaryOperator::Not),
     ));
 
-    let unary = unary_op
-        .map_with(|op, s| (op, s.span()))
-        .clone()
-        .then(atom.clone())
-        .map_with(|(op, expr), s| {
-            (
-                Expression::Unary {
-                    op,
-                    expr: Box::new(expr),
-                },
-                s.span(),
-            )
-        })
-        .or(atom.clone())
-        .boxed();
-
-    let mul = unary
-        .clone()
-        .foldl_with(
-            choice((
-                just(Token::Operator("*".to_string())).map(|_| BinaryOperator::Multiply),
-                just(Token::Operator("/".to_string())).map(|_| BinaryOperator::Divide),
-            ))
+    // Unary operators and casts apply to whatever follows them, so `-<int>$x`
+    // and `<int> <string> 1` nest.
+    let unary = recursive(|unary| {
+        unary_op
             .map_with(|op, s| (op, s.span()))
-            .padded_by(optional_new_line())
-            .then(unary)
-            .repeated(),
-            |a, (op, b), s| {
+            .then(unary.clone())
+            .map_with(|(op, expr), s| {
                 (
-                    Expression::Binary {
-                        left: Box::new(a),
+                    Expression::Unary {
                         op,
-                        right: Box::new(b),
+                        expr: Box::new(expr),
                     },
                     s.span(),
                 )
-            },
-        )
-        .boxed();
+            })
+            .or(cast_parser(unary))
+            .or(atom.clone())
+    })
+    .boxed();
+
+    // Operators bind from tightest to loosest, as in SurrealDB: `??` and `?:`
+    // first, then `**`, arithmetic, relations, equality, `AND` and `OR`.
+    let nullish = binary(
+        unary,
+        choice((
+            op("??").map(|_| BinaryOperator::NullCoalesce),
+            op("?:").map(|_| BinaryOperator::Truthy),
+        )),
+    );
 
-    let add = mul
+    // `**` is right-associative: `2 ** 3 ** 2` is `2 ** (3 ** 2)`.
+    let power = nullish
         .clone()
-        .foldl_with(
-            choice((
-                just(Token::Operator("+".to_string())).map(|_| BinaryOperator::Add),
-                just(Token::Operator("-".to_string())).map(|_| BinaryOperator::Subtract),
-                just(Token::Operator("%".to_string())).map(|_| BinaryOperator::Modulo),
-            ))
-            .map_with(|op, s| (op, s.span()))
-            .padded_by(optional_new_line())
-            .then(mul)
-            .repeated(),
-            |a, (op, b), s| {
-                (
-                    Expression::Binary {
-                        left: Box::new(a),
-                        op,
-                        right: Box::new(b),
-                    },
-                    s.span(),
-                )
-            },
+        .then(
+            op("**")
+                .map_with(|_, s| (BinaryOperator::Power, s.span()))
+                .padded_by(optional_new_line()),
         )
+        .repeated()
+        .foldr(nullish, |(a, op), b| {
+            let span = (a.1.start..b.1.end).into();
+            (
+                Expression::Binary {
+                    left: Box::new(a),
+                    op,
+                    right: Box::new(b),
+                },
+                span,
+            )
+        })
         .boxed();
 
-    let cmp = add
-        .clone()
-        .foldl_with(
-            choice((
-                just(Token::Operator("==".to_string())).map(|_| BinaryOperator::Equals),
-                just(Token::Operator("!=".to_string())).map(|_| BinaryOperator::NotEquals),
-                just(Token::Operator(">".to_string())).map(|_| BinaryOperator::GreaterThan),
-                just(Token::Operator("<".to_string())).map(|_| BinaryOperator::LessThan),
-                just(Token::Operator(">=".to_string())).map(|_| BinaryOperator::GreaterThanOrEqual),
-                just(Token::Operator("<=".to_string())).map(|_| BinaryOperator::LessThanOrEqual),
-            ))
-            .map_with(|op, s| (op, s.span()))
-            .padded_by(optional_new_line())
-            .then(add)
-            .repeated(),
-            |a, (op, b), s| {
-                (
-                    Expression::Binary {
-                        left: Box::new(a),
-                        op,
-                        right: Box::new(b),
-                    },
-                    s.span(),
-                )
-            },
+    let mul = binary(
+        power,
+        choice((
+            op("*").map(|_| BinaryOperator::Multiply),
+            op("/").map(|_| BinaryOperator::Divide),
+            op("%").map(|_| BinaryOperator::Modulo),
+        )),
+    );
+
+    let add = binary(
+        mul,
+        choice((
+            op("+").map(|_| BinaryOperator::Add),
+            op("-").map(|_| BinaryOperator::Subtract),
+        )),
+    );
+
+    let knn = op("<|")
+        .ignore_then(select! { Token::Integer(k) => k })
+        .then(
+            just(Token::Punctuation(','))
+                .ignore_then(select! {
+                    Token::Integer(s) => s,
+                    Token::Identifier(s) => s,
+                })
+                .or_not(),
         )
-        .boxed();
+        .then_ignore(op("|>"))
+        .map(|(k, param)| match param {
+            Some(param) => BinaryOperator::Knn(format!("{},{}", k, param)),
+            None => BinaryOperator::Knn(k),
+        });
 
-    let and = cmp
-        .clone()
-        .foldl_with(
-            just(Token::Operator("&&".to_string()))
-                .padded_by(optional_new_line())
-                .map_with(|_, s| (BinaryOperator::And, s.span()))
-                .then(cmp)
-                .repeated(),
-            |a, (op, b), s| {
-                (
-                    Expression::Binary {
-                        left: Box::new(a),
-                        op,
-                        right: Box::new(b),
-                    },
-                    s.span(),
-                )
+    let relation = binary(
+        add,
+        choice((
+            op(">").map(|_| BinaryOperator::GreaterThan),
+            op("<").map(|_| BinaryOperator::LessThan),
+            op(">=").map(|_| BinaryOperator::GreaterThanOrEqual),
+            op("<=").map(|_| BinaryOperator::LessThanOrEqual),
+            word("contains").map(|_| BinaryOperator::Contains),
+            word("containsnot").map(|_| BinaryOperator::ContainsNot),
+            word("containsall").map(|_| BinaryOperator::ContainsAll),
+            word("containsany").map(|_| BinaryOperator::ContainsAny),
+            word("containsnone").map(|_| BinaryOperator::ContainsNone),
+            word("inside").map(|_| BinaryOperator::Inside),
+            word("in").map(|_| BinaryOperator::Inside),
+            word("notinside").map(|_| BinaryOperator::NotInside),
+            just(Token::Keyword(Keyword::Not))
+                .then(word("in"))
+                .map(|_| BinaryOperator::NotInside),
+            word("allinside").map(|_| BinaryOperator::AllInside),
+            word("anyinside").map(|_| BinaryOperator::AnyInside),
+            word("noneinside").map(|_| BinaryOperator::NoneInside),
+            word("outside").map(|_| BinaryOperator::Outside),
+            word("intersects").map(|_| BinaryOperator::Intersects),
+            knn,
+        )),
+    );
+
+    let equality = binary(
+        relation,
+        choice((
+            op("==").map(|_| BinaryOperator::Equals),
+            op("=").map(|_| BinaryOperator::Equals),
+            op("!=").map(|_| BinaryOperator::NotEquals),
+            word("is")
+                .ignore_then(just(Token::Keyword(Keyword::Not)).or_not())
+                .map(|not| match not {
+                    Some(_) => BinaryOperator::NotEquals,
+                    None => BinaryOperator::Equals,
+                }),
+            op("~").map(|_| BinaryOperator::Like),
+            op("!~").map(|_| BinaryOperator::NotLike),
+            select! {
+                Token::Operator(s) if s.starts_with('@') => {
+                    let reference = s.trim_matches('@');
+                    BinaryOperator::Matches(
+                        (!reference.is_empty()).then(|| reference.to_string()),
+                    )
+                },
             },
-        )
-        .boxed();
+        )),
+    );
+
+    let and = binary(
+        equality,
+        choice((op("&&"), just(Token::Keyword(Keyword::And)))).map(|_| BinaryOperator::And),
+    );
 
-    let or = and
+    binary(
+        and,
+        choice((op("||"), just(Token::Keyword(Keyword::Or)))).map(|_| BinaryOperator::Or),
+    )
+}
+
+/// Folds `operand`s separated by `op` into left-associative binary
+/// expressions.
+fn binary<'tokens, 'src: 'tokens>(
+    operand: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+    op: impl Parser<'tokens, ParserInput<'tokens, 'src>, BinaryOperator, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    operand
         .clone()
         .foldl_with(
-            just(Token::Operator("||".to_string()))
+            op.map_with(|op, s| (op, s.span()))
                 .padded_by(optional_new_line())
-                .map_with(|_, s| (BinaryOperator::Or, s.span()))
-                .then(and)
+                .then(operand)
                 .repeated(),
             |a, (op, b), s| {
                 (
@@ -159,6 +208,61 @@ pub fn op_parser<'tokens, 'src: 'tokens>(
                 )
             },
         )
-        .boxed();
-    or
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{
+            expr::{literal::Literal, parser::Expression},
+            statement::statement::Statement,
+        },
+        declarations::type_::Type,
+        util::testing::{get_type, parse},
+    };
+
+    /// `src` with every binary operation wrapped in parentheses.
+    fn group(src: &str) -> String {
+        fn render(expr: &Expression) -> String {
+            match expr {
+                Expression::Binary { left, op, right } => {
+                    format!("({} {:?} {})", render(&left.0), op.0, render(&right.0))
+                }
+                Expression::Literal(Literal::Int(n)) => n.clone(),
+                Expression::Literal(Literal::Bool(b)) => b.to_string(),
+                Expression::Variable(name) => format!("${name}"),
+                expr => panic!("unexpected expression {expr:?}"),
+            }
+        }
+        match parse(&format!("RETURN {src}")).remove(0).0 {
+            Statement::Return(expr) => render(&expr.0),
+            statement => panic!("unexpected statement {statement:?}"),
+        }
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(group("1 + 2 * 3"), "(1 Add (2 Multiply 3))");
+        assert_eq!(group("1 * 2 + 3"), "((1 Multiply 2) Add 3)");
+        assert_eq!(group("1 - 2 - 3"), "((1 Subtract 2) Subtract 3)");
+        assert_eq!(group("2 ** 3 ** 2"), "(2 Power (3 Power 2))");
+        assert_eq!(group("2 * 3 ** 2"), "(2 Multiply (3 Power 2))");
+        assert_eq!(group("$a ?? 1 ** 2"), "(($a NullCoalesce 1) Power 2)");
+        assert_eq!(group("1 + 2 < 4"), "((1 Add 2) LessThan 4)");
+        assert_eq!(group("1 < 2 = true"), "((1 LessThan 2) Equals true)");
+        assert_eq!(
+            group("$a OR $b AND $c = 1"),
+            "($a Or ($b And ($c Equals 1)))"
+        );
+        assert_eq!(group("$a && $b || $c"), "(($a And $b) Or $c)");
+    }
+
+    #[test]
+    fn operation_types() {
+        let scope = Default::default();
+        assert_eq!(get_type("RETURN 1 + 2 * 3", &scope), Type::Int);
+        assert_eq!(get_type("RETURN 1 + 2 * 3 > 4", &scope), Type::Bool);
+        assert_eq!(get_type("RETURN 1 < 2 AND 3 < 4", &scope), Type::Bool);
+    }
 }
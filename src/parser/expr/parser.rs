@@ -7,13 +7,14 @@ use chumsky::{
 use crate::{
     ast::{expr::parser::Expression, parser::Extra, statement::statement::Statement},
     lexer::token::Token,
+    parser::statement::if_else::if_else_parser,
     util::span::{ParserInput, Spanned},
 };
 
 use super::{
     access::access_parser, array::array_parser, code_block::code_block_parser,
-    function::function_parser, literal::literal_parser, newline::optional_new_line,
-    object::object_parser, op::op_parser,
+    function::function_parser, graph::graph_parser, literal::literal_parser,
+    newline::optional_new_line, object::object_parser, op::op_parser,
 };
 
 pub fn expr_parser<'tokens, 'src: 'tokens>(
@@ -53,21 +54,32 @@ pub fn expr_parser<'tokens, 'src: 'tokens>(
             .map(|e| Expression::Inline(Box::new(e)))
             .map_with(|e, s| (e, s.span()));
 
+        let if_else = if_else_parser(expr.clone(), stmt.clone())
+            .map_with(|if_else, s| (Statement::IfElse(if_else), s.span()))
+            .map(|e| Expression::Inline(Box::new(e)))
+            .map_with(|e, s| (e, s.span()));
+
+        let graph = graph_parser(expr.clone())
+            .map(|graph| Expression::Graph(Box::new(graph)))
+            .map_with(|e, s| (e, s.span()))
+            .labelled("graph traversal");
+
         let atom = choice((
             literal,
+            if_else,
             function_parser(expr.clone()),
             variable,
             ident,
             bracketed_statement,
             bracketed,
+            graph,
+            array_parser(expr.clone()),
+            object_parser(expr.clone()),
+            code_block_parser(expr.clone(), stmt),
         ));
-        let access = access_parser(atom, expr.clone());
+        let access = access_parser(atom, expr);
 
-        op_parser(access.clone())
-            .or(access)
-            .or(array_parser(expr.clone()))
-            .or(object_parser(expr))
-            .or(code_block_parser(stmt))
+        op_parser(access.clone()).or(access)
     })
     .labelled("expression")
 }
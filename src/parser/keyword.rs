@@ -0,0 +1,13 @@
+use chumsky::{select, Parser};
+
+use crate::{ast::parser::Extra, lexer::token::Token, util::span::ParserInput};
+
+/// Matches `word` in any case. Keywords which aren't reserved are lexed as
+/// identifiers, so they can still name fields and tables.
+pub fn word<'tokens, 'src: 'tokens>(
+    word: &'static str,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, (), Extra<'tokens>> + Clone + 'tokens {
+    select! {
+        Token::Identifier(s) if s.eq_ignore_ascii_case(word) => (),
+    }
+}
@@ -1,4 +1,6 @@
 pub mod expr;
+pub mod keyword;
 pub mod parser;
 pub mod statement;
+pub mod target;
 pub mod type_;
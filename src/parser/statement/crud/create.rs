@@ -1,4 +1,4 @@
-use chumsky::{primitive::just, recovery::via_parser, select, IterParser, Parser};
+use chumsky::{primitive::just, recovery::via_parser, IterParser, Parser};
 
 use crate::{
     ast::{
@@ -6,11 +6,14 @@ use crate::{
         statement::{crud::create::CreateStatement, statement::Statement},
     },
     lexer::{keyword::Keyword, token::Token},
-    parser::{expr::newline::optional_new_line, statement::transform::transform_parser},
+    parser::{
+        expr::newline::optional_new_line, statement::transform::transform_parser,
+        target::target_parser,
+    },
     util::span::{ParserInput, Spanned},
 };
 
-use super::content::content_parser;
+use super::{data::data_parser, output::output_parser};
 
 pub fn create_statement_parser<'tokens, 'src: 'tokens>(
     stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
@@ -18,32 +21,60 @@ pub fn create_statement_parser<'tokens, 'src: 'tokens>(
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, CreateStatement, Extra<'tokens>> + Clone + 'tokens
 {
-    let ident = select! {
-        Token::Identifier(s) => s,
-    }
-    .map_with(|x, s| (x, s.span()));
     let create_part = just(Token::Keyword(Keyword::Create))
-        .ignore_then(optional_new_line().ignore_then(ident))
+        .ignore_then(optional_new_line().ignore_then(target_parser(stmt.clone())))
         .map(|x| Some(x))
         .recover_with(via_parser(
             just(Token::Keyword(Keyword::Create)).map(|_| None),
         ));
 
     create_part
-        .clone()
-        .then_ignore(optional_new_line())
-        .then(content_parser(stmt.clone()))
-        .recover_with(via_parser(create_part.map(|x| (x, None))))
         .then(
-            transform_parser(stmt)
+            optional_new_line()
+                .ignore_then(data_parser(stmt.clone()))
+                .map_with(|d, s| (d, s.span()))
+                .or_not(),
+        )
+        .then(
+            transform_parser(stmt.clone())
                 .map_with(|part, scope| (part, scope.span()))
                 .separated_by(optional_new_line())
                 .allow_leading()
                 .collect::<Vec<_>>(),
         )
-        .map(|((table, content), transforms)| CreateStatement {
-            table,
-            content,
+        .then(output_parser(stmt).or_not())
+        .map(|(((target, data), transforms), output)| CreateStatement {
+            target,
+            data,
             transforms,
+            output,
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_diagnostics, get_type, object, option, scope, SCHEMA},
+    };
+
+    #[test]
+    fn the_return_clause_is_applied() {
+        let scope = scope(SCHEMA);
+        let post = Type::Object(scope.table_definitions["post"].clone());
+        let create =
+            |output: &str| get_type(&format!("CREATE post SET title = 'a' {output}"), &scope);
+        assert_eq!(create(""), array(post.clone()));
+        assert_eq!(create("RETURN AFTER"), array(post.clone()));
+        assert_eq!(create("RETURN BEFORE"), array(option(post)));
+        assert_eq!(create("RETURN NONE"), array(Type::Null));
+        assert_eq!(
+            create("RETURN title"),
+            array(object(&[("title", Type::String)]))
+        );
+        assert_eq!(
+            get_diagnostics("CREATE post SET title = 'a' RETURN nope", &scope),
+            vec!["Unknown field 'nope'"]
+        );
+    }
+}
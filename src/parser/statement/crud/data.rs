@@ -0,0 +1,101 @@
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    select, IterParser, Parser,
+};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        parser::Extra,
+        statement::{
+            crud::data::{Assignment, AssignmentOperator, Data},
+            statement::Statement,
+        },
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+use super::content::content_parser;
+
+pub fn data_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Data, Extra<'tokens>> + Clone + 'tokens {
+    let expr_clause = |clause: &'static str, data: fn(Option<Spanned<Expression>>) -> Data| {
+        word(clause)
+            .ignore_then(optional_new_line().ignore_then(expr_parser(stmt.clone())))
+            .map(Some)
+            .recover_with(via_parser(word(clause).map(|_| None)))
+            .map(data)
+    };
+    let comma = just(Token::Punctuation(',')).then(optional_new_line());
+
+    let set = just(Token::Keyword(Keyword::Set))
+        .ignore_then(optional_new_line())
+        .ignore_then(
+            assignment_parser(stmt.clone())
+                .separated_by(comma.clone())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(Data::Set);
+    let unset = word("unset")
+        .ignore_then(optional_new_line())
+        .ignore_then(
+            field_path_parser()
+                .separated_by(comma)
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(Data::Unset);
+
+    choice((
+        content_parser(stmt.clone()).map(Data::Content),
+        expr_clause("replace", Data::Replace),
+        expr_clause("merge", Data::Merge),
+        expr_clause("patch", Data::Patch),
+        set,
+        unset,
+    ))
+}
+
+pub fn assignment_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Assignment>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    let op = select! {
+        Token::Operator(op) if op == "=" => AssignmentOperator::Assign,
+        Token::Operator(op) if op == "+=" => AssignmentOperator::Add,
+        Token::Operator(op) if op == "-=" => AssignmentOperator::Subtract,
+    };
+    field_path_parser()
+        .then(op)
+        .then_ignore(optional_new_line())
+        .then(expr_parser(stmt))
+        .map(|((field, op), value)| Assignment { field, op, value })
+        .map_with(|a, s| (a, s.span()))
+}
+
+fn field_path_parser<'tokens, 'src: 'tokens>(
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Vec<Spanned<String>>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    let ident = select! {
+        Token::Identifier(s) => s,
+    }
+    .map_with(|x, s| (x, s.span()));
+    ident
+        .separated_by(just(Token::Punctuation('.')))
+        .at_least(1)
+        .collect::<Vec<_>>()
+}
@@ -1,4 +1,4 @@
-use chumsky::{primitive::just, recovery::via_parser, select, IterParser, Parser};
+use chumsky::{primitive::just, recovery::via_parser, IterParser, Parser};
 
 use crate::{
     ast::{
@@ -6,7 +6,10 @@ use crate::{
         statement::{crud::delete::DeleteStatement, statement::Statement},
     },
     lexer::{keyword::Keyword, token::Token},
-    parser::{expr::newline::optional_new_line, statement::transform::transform_parser},
+    parser::{
+        expr::newline::optional_new_line, statement::transform::transform_parser,
+        target::target_parser,
+    },
     util::span::{ParserInput, Spanned},
 };
 
@@ -16,15 +19,11 @@ pub fn delete_statement_parser<'tokens, 'src: 'tokens>(
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, DeleteStatement, Extra<'tokens>> + Clone + 'tokens
 {
-    let ident = select! {
-        Token::Identifier(s) => s,
-    }
-    .map_with(|x, s| (x, s.span()));
     let delete_part = just(Token::Keyword(Keyword::Delete))
-        .ignore_then(optional_new_line().ignore_then(ident))
+        .ignore_then(optional_new_line().ignore_then(target_parser(stmt.clone())))
         .map(|x| Some(x))
         .recover_with(via_parser(
-            just(Token::Keyword(Keyword::Create)).map(|_| None),
+            just(Token::Keyword(Keyword::Delete)).map(|_| None),
         ));
 
     delete_part
@@ -37,5 +36,5 @@ pub fn delete_statement_parser<'tokens, 'src: 'tokens>(
                 .allow_leading()
                 .collect::<Vec<_>>(),
         )
-        .map(|(table, transforms)| DeleteStatement { table, transforms })
+        .map(|(target, transforms)| DeleteStatement { target, transforms })
 }
@@ -0,0 +1,154 @@
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    select, IterParser, Parser,
+};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{
+            crud::insert::{InsertStatement, InsertValues},
+            statement::Statement,
+        },
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+use super::{data::assignment_parser, output::output_parser};
+
+pub fn insert_statement_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, InsertStatement, Extra<'tokens>> + Clone + 'tokens
+{
+    let ident = select! {
+        Token::Identifier(s) => s,
+    }
+    .map_with(|x, s| (x, s.span()));
+    let comma = just(Token::Punctuation(',')).then(optional_new_line());
+
+    let insert = just(Token::Keyword(Keyword::Insert))
+        .ignore_then(optional_new_line())
+        .ignore_then(word("ignore").then_ignore(optional_new_line()).or_not())
+        .map(|ignore| ignore.is_some());
+    let insert_part = insert
+        .clone()
+        .then_ignore(word("into"))
+        .then_ignore(optional_new_line())
+        .then(ident.map(Some))
+        .recover_with(via_parser(
+            insert
+                .then_ignore(word("into").or_not())
+                .map(|ignore| (ignore, None)),
+        ));
+
+    let fields = ident
+        .separated_by(comma.clone())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(
+            just(Token::Punctuation('(')).then(optional_new_line()),
+            optional_new_line().then(just(Token::Punctuation(')'))),
+        );
+    let row = expr_parser(stmt.clone())
+        .separated_by(comma.clone())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(
+            just(Token::Punctuation('(')).then(optional_new_line()),
+            optional_new_line().then(just(Token::Punctuation(')'))),
+        )
+        .map_with(|row, s| (row, s.span()));
+    let values = fields
+        .then_ignore(optional_new_line())
+        .then_ignore(word("values"))
+        .then_ignore(optional_new_line())
+        .then(
+            row.separated_by(comma.clone())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(|(fields, rows)| InsertValues::Values { fields, rows });
+    let content = expr_parser(stmt.clone()).map(InsertValues::Content);
+
+    let on_duplicate = just(Token::Keyword(Keyword::On))
+        .then(word("duplicate"))
+        .then(word("key"))
+        .then(just(Token::Keyword(Keyword::Update)))
+        .then(optional_new_line())
+        .ignore_then(
+            assignment_parser(stmt.clone())
+                .separated_by(comma)
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        );
+
+    insert_part
+        .clone()
+        .then_ignore(optional_new_line())
+        .then(choice((values, content)).map_with(|v, s| (v, s.span())))
+        .map(|(insert, values)| (insert, Some(values)))
+        .recover_with(via_parser(insert_part.map(|insert| (insert, None))))
+        .then(optional_new_line().ignore_then(on_duplicate).or_not())
+        .then(output_parser(stmt).or_not())
+        .map(
+            |((((ignore, table), values), on_duplicate), output)| InsertStatement {
+                ignore,
+                table,
+                values,
+                on_duplicate,
+                output,
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_diagnostics, get_type, object, option, scope, SCHEMA},
+    };
+
+    #[test]
+    fn the_return_clause_is_applied() {
+        let scope = scope(SCHEMA);
+        let post = Type::Object(scope.table_definitions["post"].clone());
+        let insert = |output: &str| {
+            get_type(
+                &format!("INSERT INTO post {{ title: 'a' }} {output}"),
+                &scope,
+            )
+        };
+        assert_eq!(insert(""), array(post.clone()));
+        assert_eq!(insert("RETURN AFTER"), array(post.clone()));
+        assert_eq!(insert("RETURN BEFORE"), array(option(post)));
+        assert_eq!(insert("RETURN NONE"), array(Type::Null));
+        assert_eq!(
+            insert("RETURN title"),
+            array(object(&[("title", Type::String)]))
+        );
+    }
+
+    #[test]
+    fn rows_in_variables_are_checked_field_by_field() {
+        let scope = scope(SCHEMA);
+        for src in [
+            "LET $rows = [{ name: 'a', age: 1 }]; INSERT INTO person $rows",
+            "LET $row = { name: 'a', age: 1 }; INSERT INTO person $row",
+            "LET $row = { name: 'a', age: 1 }; CREATE person CONTENT $row",
+        ] {
+            assert!(get_diagnostics(src, &scope).is_empty(), "{src}");
+        }
+        for rows in ["[{ name: 'a' }]", "[{ name: 'a', age: 1, nope: 1 }]"] {
+            let src = format!("LET $rows = {rows}; INSERT INTO person $rows");
+            assert_eq!(get_diagnostics(&src, &scope).len(), 1, "{src}");
+        }
+    }
+}
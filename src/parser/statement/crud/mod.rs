@@ -1,6 +1,11 @@
 pub mod content;
 pub mod create;
+pub mod data;
 pub mod delete;
+pub mod insert;
+pub mod output;
 pub mod projection;
+pub mod relate;
 pub mod select;
 pub mod update;
+pub mod upsert;
@@ -0,0 +1,50 @@
+use chumsky::{
+    primitive::{choice, just},
+    select, IterParser, Parser,
+};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{crud::output::Output, statement::Statement},
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::expr::newline::optional_new_line,
+    util::span::{ParserInput, Spanned},
+};
+
+use super::projection::projection_parser;
+
+pub fn output_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Output>, Extra<'tokens>> + Clone + 'tokens
+{
+    // BEFORE, AFTER and DIFF aren't reserved, so they can still name fields.
+    let keyword = select! {
+        Token::Keyword(Keyword::None) => Output::None,
+        Token::Identifier(s) if s.eq_ignore_ascii_case("before") => Output::Before,
+        Token::Identifier(s) if s.eq_ignore_ascii_case("after") => Output::After,
+        Token::Identifier(s) if s.eq_ignore_ascii_case("diff") => Output::Diff,
+    };
+    let keyword_output = just(Token::Keyword(Keyword::Return))
+        .ignore_then(keyword)
+        .map_with(|o, s| (o, s.span()));
+    let fields_output = just(Token::Keyword(Keyword::Return))
+        .ignore_then(
+            projection_parser(stmt)
+                .separated_by(just(Token::Punctuation(',')).then(optional_new_line()))
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(Output::Fields)
+        .map_with(|o, s| (o, s.span()));
+
+    // A RETURN on the next line only continues the statement when it can't be
+    // a RETURN statement of its own.
+    choice((
+        optional_new_line().ignore_then(keyword_output.clone()),
+        fields_output,
+    ))
+}
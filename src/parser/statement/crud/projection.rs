@@ -1,7 +1,13 @@
-use chumsky::{primitive::just, select, Parser};
+use chumsky::{
+    primitive::{choice, just},
+    select, Parser,
+};
 
 use crate::{
-    ast::{parser::Extra, projection::Projection, statement::statement::Statement},
+    ast::{
+        expr::parser::Expression, parser::Extra, projection::Projection,
+        statement::statement::Statement,
+    },
     lexer::{keyword::Keyword, token::Token},
     parser::expr::{newline::optional_new_line, parser::expr_parser},
     util::span::{ParserInput, Spanned},
@@ -21,7 +27,10 @@ pub fn projection_parser<'tokens, 'src: 'tokens>(
     let alias = just(Token::Keyword(Keyword::As))
         .padded_by(optional_new_line())
         .ignore_then(identifier_parser);
-    let projection = expr_parser(stmt)
+    let all = just(Token::Operator("*".to_string()))
+        .map(|_| Expression::All)
+        .map_with(|e, s| (e, s.span()));
+    let projection = choice((all, expr_parser(stmt)))
         .then(alias.or_not())
         .map(|(expr, alias)| Projection { expr, alias })
         .map_with(|p, span| (p, span.span()));
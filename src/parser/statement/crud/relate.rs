@@ -0,0 +1,183 @@
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    select, Parser,
+};
+
+use crate::{
+    ast::{
+        expr::{
+            access::{Access, Direction, Graph},
+            parser::Expression,
+        },
+        parser::Extra,
+        statement::{crud::relate::RelateStatement, statement::Statement},
+        target::Target,
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        target::record_id_parser,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+use super::{data::data_parser, output::output_parser};
+
+pub fn relate_statement_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, RelateStatement, Extra<'tokens>> + Clone + 'tokens
+{
+    let ident = select! {
+        Token::Identifier(s) => s,
+    }
+    .map_with(|x, s| (x, s.span()));
+    let arrow = just(Token::Operator("->".to_string()));
+    let expr = expr_parser(stmt.clone());
+    let from_node = choice((
+        record_id_parser(expr.clone()).map_with(|target, s| ((Box::new(target), s.span()), None)),
+        expr.clone().map(|expr| {
+            let (expr, edge) = split_edge(expr);
+            let span = expr.1;
+            ((Box::new(Target::Expression(expr)), span), edge)
+        }),
+    ));
+    let to_node = choice((record_id_parser(expr.clone()), expr.map(Target::Expression)))
+        .map_with(|target, s| (Box::new(target), s.span()));
+    // Arrays, objects and record ids do not take accesses, so their edge is
+    // parsed here.
+    let edge = arrow.clone().ignore_then(ident);
+    let from = just(Token::Keyword(Keyword::Relate))
+        .ignore_then(optional_new_line())
+        .ignore_then(from_node)
+        .then(edge.or_not())
+        .map(|((from, edge), explicit_edge)| (from, edge.or(explicit_edge)));
+
+    let relate_part = from
+        .clone()
+        .then_ignore(arrow.clone())
+        .then(to_node)
+        .map(|((from, edge), to)| (Some(from), edge, Some(to)))
+        .recover_with(via_parser(
+            from.then_ignore(arrow.or_not())
+                .map(|(from, edge)| (Some(from), edge, None)),
+        ))
+        .recover_with(via_parser(
+            just(Token::Keyword(Keyword::Relate)).map(|_| (None, None, None)),
+        ));
+
+    relate_part
+        .then(
+            optional_new_line()
+                .ignore_then(data_parser(stmt.clone()))
+                .map_with(|d, s| (d, s.span()))
+                .or_not(),
+        )
+        .then(output_parser(stmt).or_not())
+        .map(|(((from, edge, to), data), output)| RelateStatement {
+            from,
+            edge,
+            to,
+            data,
+            output,
+        })
+}
+
+/// The expression parser reads `$from->edge` as a graph traversal, so the
+/// final outgoing step is split back off as the edge table.
+fn split_edge(from: Spanned<Expression>) -> (Spanned<Expression>, Option<Spanned<String>>) {
+    if let (Expression::Access { expr, access }, _) = &from {
+        if let Access::Graph(Graph {
+            direction: Direction::Out,
+            table,
+            condition: None,
+        }) = access.0.as_ref()
+        {
+            return (expr.as_ref().clone(), Some(table.clone()));
+        }
+    }
+    (from, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{
+            array, get_diagnostics, get_located_diagnostics, get_type, object, option, scope,
+            SCHEMA,
+        },
+    };
+
+    const NODES: &str = "
+        LET $p = CREATE person SET name = 'a', age = 1;
+        LET $q = CREATE post SET title = 'b';
+    ";
+
+    #[test]
+    fn created_records_can_be_related() {
+        let scope = scope(SCHEMA);
+        let src = format!("{NODES} RELATE $p->likes->$q");
+        assert!(get_diagnostics(&src, &scope).is_empty());
+        let src = format!("{NODES} RELATE $q->likes->$p");
+        assert_eq!(
+            get_diagnostics(&src, &scope),
+            vec![
+                "Expected type array<record<person>>, found type array<record<post>>",
+                "Expected type array<record<post>>, found type array<record<person>>",
+            ]
+        );
+    }
+
+    #[test]
+    fn record_ids_can_be_related() {
+        let scope = scope(SCHEMA);
+        for src in [
+            "RELATE person:a->likes->post:b",
+            "RELATE person:⟨a b⟩->likes->post:[1, 'b']",
+            "RELATE person:1..3->likes->(SELECT * FROM post)",
+        ] {
+            assert!(
+                get_diagnostics(&format!("{NODES} {src}"), &scope).is_empty(),
+                "{src}"
+            );
+        }
+        assert_eq!(
+            get_located_diagnostics("RELATE post:a->likes->person:b", &scope),
+            vec![
+                (
+                    "post:a".to_string(),
+                    "Expected type record<person>, found type record<post>".to_string()
+                ),
+                (
+                    "person:b".to_string(),
+                    "Expected type record<post>, found type record<person>".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            get_diagnostics("RELATE nope:a->likes->post:b", &scope),
+            vec![
+                "Table 'nope' not found",
+                "Expected type record<person>, found type record<nope>",
+            ]
+        );
+    }
+
+    #[test]
+    fn the_return_clause_is_applied() {
+        let scope = scope(SCHEMA);
+        let likes = Type::Object(scope.table_definitions["likes"].clone());
+        let relate =
+            |output: &str| get_type(&format!("{NODES} RELATE $p->likes->$q {output}"), &scope);
+        assert_eq!(relate(""), array(likes.clone()));
+        assert_eq!(relate("RETURN NONE"), array(Type::Null));
+        assert_eq!(relate("RETURN BEFORE"), array(option(likes)));
+        assert_eq!(
+            relate("RETURN out AS post"),
+            array(object(&[("post", Type::Record("post".to_string()))]))
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use chumsky::{primitive::just, recovery::via_parser, select, IterParser, Parser};
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    IterParser, Parser,
+};
 
 use crate::{
     ast::{
@@ -9,7 +13,12 @@ use crate::{
         },
     },
     lexer::{keyword::Keyword, token::Token},
-    parser::{expr::newline::optional_new_line, statement::transform::transform_parser},
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+        statement::transform::select_transform_parser,
+        target::target_parser,
+    },
     util::span::{ParserInput, Spanned},
 };
 
@@ -21,38 +30,189 @@ pub fn select_statement_parser<'tokens, 'src: 'tokens>(
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, SelectStatement, Extra<'tokens>> + Clone + 'tokens
 {
-    let ident = select! {
-        Token::Identifier(s) => s,
-    }
-    .map_with(|x, s| (x, s.span()));
-
+    let comma = just(Token::Punctuation(',')).then(optional_new_line());
     let projections = projection_parser(stmt.clone())
         .separated_by(just(Token::Punctuation(',')))
         .collect::<Vec<_>>();
+    let value = word("value")
+        .ignore_then(projection_parser(stmt.clone()))
+        .map(|projection| (true, vec![projection]));
+    let omit = optional_new_line()
+        .ignore_then(word("omit"))
+        .ignore_then(optional_new_line())
+        .ignore_then(
+            expr_parser(stmt.clone())
+                .separated_by(comma.clone())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .or_not()
+        .map(Option::unwrap_or_default);
 
     let select_part = just(Token::Keyword(Keyword::Select))
-        .ignore_then(optional_new_line().ignore_then(projections));
+        .ignore_then(optional_new_line())
+        .ignore_then(choice((value, projections.map(|p| (false, p)))))
+        .then(omit);
 
+    let targets = target_parser(stmt.clone())
+        .separated_by(comma)
+        .at_least(1)
+        .collect::<Vec<_>>();
     let from_part = just(Token::Keyword(Keyword::From))
-        .ignore_then(optional_new_line().ignore_then(ident))
-        .map(|x| Some(x))
+        .ignore_then(optional_new_line())
+        .ignore_then(choice((
+            word("only")
+                .ignore_then(optional_new_line())
+                .ignore_then(targets.clone())
+                .map(|from| (true, from)),
+            targets.map(|from| (false, from)),
+        )))
         .recover_with(via_parser(
-            just(Token::Keyword(Keyword::From)).map(|_| None),
+            just(Token::Keyword(Keyword::From)).map(|_| (false, vec![])),
         ));
     select_part
         .clone()
         .then(optional_new_line().ignore_then(from_part))
-        .recover_with(via_parser(select_part.map(|x| (x, None))))
+        .recover_with(via_parser(select_part.map(|x| (x, (false, vec![])))))
         .then(
-            transform_parser(stmt)
+            select_transform_parser(stmt)
                 .map_with(|part, scope| (part, scope.span()))
                 .separated_by(optional_new_line())
                 .allow_leading()
                 .collect::<Vec<_>>(),
         )
-        .map(|((projections, from), transforms)| SelectStatement {
-            projections,
-            from,
-            transforms,
-        })
+        .map(
+            |((((value, projections), omit), (only, from)), transforms)| SelectStatement {
+                value,
+                projections,
+                omit,
+                only,
+                from,
+                transforms,
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{
+            array, get_diagnostics, get_located_diagnostics, get_type, object, option, scope,
+            SCHEMA,
+        },
+    };
+
+    #[test]
+    fn projections_are_typed_by_the_target() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_type("SELECT name, age AS years FROM person", &scope),
+            array(object(&[("name", Type::String), ("years", Type::Int)]))
+        );
+        assert_eq!(
+            get_type("SELECT VALUE title FROM post", &scope),
+            array(Type::String)
+        );
+    }
+
+    #[test]
+    fn each_target_is_typed_as_its_own_record() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_type("SELECT name, title FROM person, post", &scope),
+            array(object(&[
+                ("name", option(Type::String)),
+                ("title", option(Type::String)),
+            ]))
+        );
+        let Type::Array(item) = get_type("SELECT * FROM person, post", &scope) else {
+            panic!("a SELECT without ONLY should return an array");
+        };
+        let field_type = |name: &str| item.get_field(name).map(|field| field.ty.clone());
+        assert_eq!(field_type("name"), Some(option(Type::String)));
+        assert_eq!(field_type("title"), Some(option(Type::String)));
+        assert_eq!(field_type("id"), Some(Type::Any));
+    }
+
+    #[test]
+    fn graph_projections_are_nested_by_step() {
+        let scope = scope(SCHEMA);
+        let titles = object(&[("title", array(Type::String))]);
+        assert_eq!(
+            get_type("SELECT ->likes->post.title FROM person", &scope),
+            array(object(&[("->likes", object(&[("->post", titles)]))]))
+        );
+        assert_eq!(
+            get_type("SELECT <-likes AS fans FROM post", &scope),
+            array(object(&[(
+                "fans",
+                array(Type::Record("likes".to_string()))
+            )]))
+        );
+    }
+
+    #[test]
+    fn optional_records_are_followed() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_type("SELECT friend.name FROM person", &scope),
+            array(object(&[(
+                "friend",
+                object(&[("name", option(Type::String))])
+            )]))
+        );
+        let person = Type::Object(scope.table_definitions["person"].clone());
+        assert_eq!(
+            get_type("SELECT friend.* FROM person", &scope),
+            array(object(&[("friend", option(person))]))
+        );
+        assert_eq!(
+            get_type("SELECT VALUE friend.friend.age FROM person", &scope),
+            array(option(Type::Int))
+        );
+        assert!(get_diagnostics("SELECT friend.name, friend.* FROM person", &scope).is_empty());
+    }
+
+    #[test]
+    fn grouped_selects_only_read_grouped_or_aggregated_fields() {
+        let scope = scope(SCHEMA);
+        assert_eq!(
+            get_type(
+                "SELECT name, count() AS total, math::mean(age) AS mean FROM person GROUP BY name",
+                &scope
+            ),
+            array(object(&[
+                ("name", Type::String),
+                ("total", Type::Int),
+                ("mean", Type::Float),
+            ]))
+        );
+        assert_eq!(
+            get_type(
+                "SELECT array::group(age) AS ages FROM person GROUP ALL",
+                &scope
+            ),
+            array(object(&[("ages", array(Type::Any))]))
+        );
+        assert!(get_diagnostics(
+            "SELECT name AS n, count() AS total FROM person GROUP BY n",
+            &scope
+        )
+        .is_empty());
+        assert_eq!(
+            get_located_diagnostics(
+                "SELECT name, age + 1 AS next FROM person GROUP BY name",
+                &scope
+            ),
+            vec![(
+                "age".to_string(),
+                "Field age must be grouped or used in an aggregate function".to_string()
+            )]
+        );
+        assert_eq!(
+            get_diagnostics("SELECT age FROM person GROUP BY friend.name", &scope),
+            vec!["Field age must be grouped or used in an aggregate function"]
+        );
+    }
 }
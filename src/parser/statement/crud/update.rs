@@ -1,4 +1,4 @@
-use chumsky::{primitive::just, recovery::via_parser, select, IterParser, Parser};
+use chumsky::{primitive::just, recovery::via_parser, IterParser, Parser};
 
 use crate::{
     ast::{
@@ -6,11 +6,14 @@ use crate::{
         statement::{crud::update::UpdateStatement, statement::Statement},
     },
     lexer::{keyword::Keyword, token::Token},
-    parser::{expr::newline::optional_new_line, statement::transform::transform_parser},
+    parser::{
+        expr::newline::optional_new_line, statement::transform::transform_parser,
+        target::target_parser,
+    },
     util::span::{ParserInput, Spanned},
 };
 
-use super::content::content_parser;
+use super::{data::data_parser, output::output_parser};
 
 pub fn update_statement_parser<'tokens, 'src: 'tokens>(
     stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
@@ -18,33 +21,60 @@ pub fn update_statement_parser<'tokens, 'src: 'tokens>(
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, UpdateStatement, Extra<'tokens>> + Clone + 'tokens
 {
-    let ident = select! {
-        Token::Identifier(s) => s,
-    }
-    .map_with(|x, s| (x, s.span()));
     let update_part = just(Token::Keyword(Keyword::Update))
-        .ignore_then(optional_new_line().ignore_then(ident))
+        .ignore_then(optional_new_line().ignore_then(target_parser(stmt.clone())))
         .map(|x| Some(x))
         .recover_with(via_parser(
             just(Token::Keyword(Keyword::Update)).map(|_| None),
         ));
 
     update_part
-        .clone()
-        .then_ignore(optional_new_line())
-        .then(content_parser(stmt.clone()))
-        .recover_with(via_parser(update_part.map(|x| (x, None))))
+        .then(
+            optional_new_line()
+                .ignore_then(data_parser(stmt.clone()))
+                .map_with(|d, s| (d, s.span()))
+                .or_not(),
+        )
         .then_ignore(optional_new_line())
         .then(
-            transform_parser(stmt)
+            transform_parser(stmt.clone())
                 .map_with(|part, scope| (part, scope.span()))
                 .separated_by(optional_new_line())
                 .allow_leading()
                 .collect::<Vec<_>>(),
         )
-        .map(|((table, content), transforms)| UpdateStatement {
-            table,
-            content,
+        .then(output_parser(stmt).or_not())
+        .map(|(((target, data), transforms), output)| UpdateStatement {
+            target,
+            data,
             transforms,
+            output,
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_type, object, option, scope, SCHEMA},
+    };
+
+    #[test]
+    fn the_return_clause_is_applied() {
+        let scope = scope(SCHEMA);
+        let person = Type::Object(scope.table_definitions["person"].clone());
+        let update = |output: &str| {
+            get_type(
+                &format!("UPDATE person SET age += 1 WHERE age > 1 {output}"),
+                &scope,
+            )
+        };
+        assert_eq!(update(""), array(person.clone()));
+        assert_eq!(update("RETURN BEFORE"), array(option(person)));
+        assert_eq!(update("RETURN DIFF").to_string(), "array<array<object>>");
+        assert_eq!(
+            update("RETURN age AS years"),
+            array(object(&[("years", Type::Int)]))
+        );
+    }
+}
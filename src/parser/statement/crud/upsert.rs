@@ -0,0 +1,88 @@
+use chumsky::{primitive::just, recovery::via_parser, IterParser, Parser};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{crud::upsert::UpsertStatement, statement::Statement},
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::newline::optional_new_line, statement::transform::transform_parser,
+        target::target_parser,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+use super::{data::data_parser, output::output_parser};
+
+pub fn upsert_statement_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, UpsertStatement, Extra<'tokens>> + Clone + 'tokens
+{
+    let upsert_part = just(Token::Keyword(Keyword::Upsert))
+        .ignore_then(optional_new_line().ignore_then(target_parser(stmt.clone())))
+        .map(Some)
+        .recover_with(via_parser(
+            just(Token::Keyword(Keyword::Upsert)).map(|_| None),
+        ));
+
+    upsert_part
+        .then(
+            optional_new_line()
+                .ignore_then(data_parser(stmt.clone()))
+                .map_with(|d, s| (d, s.span()))
+                .or_not(),
+        )
+        .then_ignore(optional_new_line())
+        .then(
+            transform_parser(stmt.clone())
+                .map_with(|part, scope| (part, scope.span()))
+                .separated_by(optional_new_line())
+                .allow_leading()
+                .collect::<Vec<_>>(),
+        )
+        .then(output_parser(stmt).or_not())
+        .map(|(((target, data), transforms), output)| UpsertStatement {
+            target,
+            data,
+            transforms,
+            output,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_type, object, option, scope, SCHEMA},
+    };
+
+    #[test]
+    fn the_return_clause_is_applied() {
+        let scope = scope(SCHEMA);
+        let upsert =
+            |output: &str| get_type(&format!("UPSERT person SET age = 1 {output}"), &scope);
+        assert_eq!(
+            upsert(""),
+            array(Type::Object(scope.table_definitions["person"].clone()))
+        );
+        assert_eq!(upsert("RETURN NONE"), array(Type::Null));
+        assert_eq!(
+            upsert("RETURN age + 1 AS next"),
+            array(object(&[("next", Type::Int)]))
+        );
+        let Type::Array(diff) = upsert("RETURN DIFF") else {
+            panic!("UPSERT should return an array");
+        };
+        assert_eq!(
+            diff.as_ref(),
+            &array(object(&[
+                ("op", Type::String),
+                ("path", Type::String),
+                ("value", option(Type::Any)),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use chumsky::{primitive::just, select, Parser};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{for_::ForStatement, statement::Statement},
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::{code_block::code_block_parser, newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+pub fn for_statement_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, ForStatement, Extra<'tokens>> + Clone + 'tokens
+{
+    let var = select! {
+        Token::Variable(var) => var,
+    }
+    .map_with(|v, s| (v, s.span()));
+    let expr = expr_parser(stmt.clone());
+
+    just(Token::Keyword(Keyword::For))
+        .ignore_then(optional_new_line())
+        .ignore_then(var)
+        .then_ignore(optional_new_line())
+        .then_ignore(word("in"))
+        .then_ignore(optional_new_line())
+        .then(expr.clone())
+        .then_ignore(optional_new_line())
+        .then(code_block_parser(expr, stmt))
+        .map(|((variable, iterable), body)| ForStatement {
+            variable,
+            iterable,
+            body,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::statement::statement::Statement,
+        declarations::{scoped_item::ScopedItems, type_::Type},
+        util::testing::{array, get_diagnostics, option, parse, scope, SCHEMA},
+    };
+
+    /// The type of the loop variable of `FOR $item IN {iterable} {}`.
+    fn get_item_type(iterable: &str, scope: &ScopedItems) -> Type {
+        match parse(&format!("FOR $item IN {iterable} {{}}")).remove(0).0 {
+            Statement::For(for_) => for_.get_item_type(scope),
+            statement => panic!("unexpected statement {statement:?}"),
+        }
+    }
+
+    #[test]
+    fn loop_variable_is_an_item() {
+        let mut scope = scope(SCHEMA);
+        scope
+            .variables
+            .insert("names".to_string(), option(array(Type::String)));
+        assert_eq!(get_item_type("[1, 2]", &scope), Type::Int);
+        assert_eq!(
+            get_item_type("(SELECT VALUE age FROM person)", &scope),
+            Type::Int
+        );
+        assert_eq!(get_item_type("$names", &scope), option(Type::String));
+        assert_eq!(get_item_type("'a'", &scope), Type::Any);
+    }
+
+    #[test]
+    fn body_and_iterable_are_checked() {
+        let scope = scope(SCHEMA);
+        assert!(get_diagnostics("FOR $n IN [1, 2] { LET $m = $n + 1; }", &scope).is_empty());
+        assert_eq!(
+            get_diagnostics("FOR $n IN 1 { BREAK; }", &scope),
+            vec!["Expected type array<any>, found type int"]
+        );
+    }
+
+    #[test]
+    fn break_and_continue_only_inside_loops() {
+        let scope = scope(SCHEMA);
+        assert!(get_diagnostics(
+            "FOR $n IN [1] { IF $n > 1 { BREAK } ELSE { CONTINUE } }",
+            &scope
+        )
+        .is_empty());
+        assert_eq!(
+            get_diagnostics("BREAK; CONTINUE", &scope),
+            vec![
+                "BREAK can only be used inside a FOR loop",
+                "CONTINUE can only be used inside a FOR loop",
+            ]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use chumsky::{primitive::choice, IterParser, Parser};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        parser::Extra,
+        statement::{if_else::IfElseStatement, statement::Statement},
+    },
+    parser::{
+        expr::{code_block::code_block_parser, newline::optional_new_line},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+/// Takes the expression parser as well as the statement parser, as `IF` can
+/// be used as an expression too.
+pub fn if_else_parser<'tokens, 'src: 'tokens>(
+    expr: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, IfElseStatement, Extra<'tokens>> + Clone + 'tokens
+{
+    let condition = optional_new_line()
+        .ignore_then(expr.clone())
+        .then_ignore(optional_new_line());
+    let else_ = optional_new_line()
+        .then(word("else"))
+        .then(optional_new_line());
+    let else_if = else_.clone().then(word("if"));
+
+    let block = code_block_parser(expr.clone(), stmt);
+    let block_branch = condition.clone().then(block.clone());
+    let braced = word("if")
+        .ignore_then(block_branch.clone())
+        .then(
+            else_if
+                .clone()
+                .ignore_then(block_branch)
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .then(else_.clone().ignore_then(block).or_not());
+
+    let value = optional_new_line().ignore_then(expr);
+    let then_branch = condition.then_ignore(word("then")).then(value.clone());
+    let classic = word("if")
+        .ignore_then(then_branch.clone())
+        .then(
+            else_if
+                .ignore_then(then_branch)
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .then(else_.ignore_then(value).or_not())
+        .then_ignore(optional_new_line())
+        .then_ignore(word("end"));
+
+    choice((braced, classic)).map(|((first, rest), otherwise)| IfElseStatement {
+        branches: std::iter::once(first).chain(rest).collect(),
+        otherwise,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{get_diagnostics, get_type, get_types, option, scope, SCHEMA},
+    };
+
+    #[test]
+    fn blocks_return_their_last_value() {
+        let scope = Default::default();
+        assert_eq!(
+            get_type("RETURN IF $c { 1 } ELSE { 2.5 }", &scope),
+            Type::Float
+        );
+        assert_eq!(
+            get_type("IF $c { LET $a = 1; $a + 1 } ELSE { 2 }", &scope),
+            Type::Int
+        );
+        assert!(get_diagnostics("LET $a = IF true { 1 } ELSE { 2.5 }", &scope).is_empty());
+    }
+
+    #[test]
+    fn branch_types_are_merged() {
+        let scope = Default::default();
+        assert_eq!(
+            get_types("LET $a = IF true { 1 } ELSE { 2.5 }; RETURN $a", &scope),
+            vec![Type::Null, Type::Float]
+        );
+        assert_eq!(
+            get_type("IF true THEN 'a' END", &scope),
+            Type::Option(Box::new(Type::String))
+        );
+    }
+
+    #[test]
+    fn any_value_can_be_a_condition() {
+        let mut scope = scope(SCHEMA);
+        scope.variables.insert(
+            "user".to_string(),
+            option(Type::Record("person".to_string())),
+        );
+        for condition in [
+            "$user",
+            "$user.name",
+            "array::first([1])",
+            "[1]",
+            "{ a: 1 }",
+            "0",
+        ] {
+            let src = format!("IF {condition} {{ 1 }}");
+            assert!(get_diagnostics(&src, &scope).is_empty(), "{src}");
+        }
+        assert_eq!(
+            get_diagnostics("IF NULL { 1 }", &scope),
+            vec!["Condition is always falsy"]
+        );
+    }
+}
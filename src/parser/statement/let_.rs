@@ -1,7 +1,12 @@
-use chumsky::{primitive::just, recovery::via_parser, select, Parser};
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    select, Parser,
+};
 
 use crate::{
     ast::{
+        expr::parser::Expression,
         parser::Extra,
         statement::{let_::LetStatement, statement::Statement},
     },
@@ -10,6 +15,13 @@ use crate::{
     util::span::{ParserInput, Spanned},
 };
 
+use super::crud::{
+    create::create_statement_parser, delete::delete_statement_parser,
+    insert::insert_statement_parser, relate::relate_statement_parser,
+    select::select_statement_parser, update::update_statement_parser,
+    upsert::upsert_statement_parser,
+};
+
 pub fn let_statement_parser<'tokens, 'src: 'tokens>(
     stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
         + Clone
@@ -23,11 +35,24 @@ pub fn let_statement_parser<'tokens, 'src: 'tokens>(
     let let_part =
         just(Token::Keyword(Keyword::Let)).ignore_then(optional_new_line().ignore_then(var));
 
+    // `LET $a = CREATE ...` holds what the statement returns.
+    let statement = choice((
+        create_statement_parser(stmt.clone()).map(Statement::Create),
+        update_statement_parser(stmt.clone()).map(Statement::Update),
+        upsert_statement_parser(stmt.clone()).map(Statement::Upsert),
+        delete_statement_parser(stmt.clone()).map(Statement::Delete),
+        insert_statement_parser(stmt.clone()).map(Statement::Insert),
+        relate_statement_parser(stmt.clone()).map(Statement::Relate),
+        select_statement_parser(stmt.clone()).map(Statement::Select),
+    ))
+    .map_with(|s, span| Expression::Inline(Box::new((s, span.span()))))
+    .map_with(|e, span| (e, span.span()));
+
     let_part
         .clone()
         .then_ignore(just(Token::Operator("=".to_string())))
         .then_ignore(optional_new_line())
-        .then(expr_parser(stmt))
+        .then(choice((statement, expr_parser(stmt))))
         .map(|(var, expr)| LetStatement {
             name: Some(var),
             value: Some(expr),
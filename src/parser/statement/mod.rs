@@ -1,8 +1,11 @@
 pub mod crud;
 pub mod define;
+pub mod for_;
+pub mod if_else;
 pub mod invalid;
 pub mod let_;
 pub mod return_;
 pub mod statement;
 pub mod statement_start;
+pub mod throw;
 pub mod transform;
@@ -1,19 +1,31 @@
-use chumsky::{primitive::choice, recovery::via_parser, recursive::recursive, Parser};
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    recursive::recursive,
+    Parser,
+};
 
 use crate::{
     ast::{parser::Extra, statement::statement::Statement},
+    lexer::{keyword::Keyword, token::Token},
+    parser::expr::parser::expr_parser,
     util::span::{ParserInput, Spanned},
 };
 
 use super::{
     crud::{
         create::create_statement_parser, delete::delete_statement_parser,
+        insert::insert_statement_parser, relate::relate_statement_parser,
         select::select_statement_parser, update::update_statement_parser,
+        upsert::upsert_statement_parser,
     },
     define::define_statement_parser,
+    for_::for_statement_parser,
+    if_else::if_else_parser,
     invalid::invalid_statement_parser,
     let_::let_statement_parser,
     return_::return_statement_parser,
+    throw::throw_statement_parser,
 };
 
 pub fn statement_parser<'tokens, 'src: 'tokens>(
@@ -23,11 +35,19 @@ pub fn statement_parser<'tokens, 'src: 'tokens>(
         let statement = choice((
             create_statement_parser(stmt.clone()).map(Statement::Create),
             update_statement_parser(stmt.clone()).map(Statement::Update),
+            upsert_statement_parser(stmt.clone()).map(Statement::Upsert),
             delete_statement_parser(stmt.clone()).map(Statement::Delete),
+            insert_statement_parser(stmt.clone()).map(Statement::Insert),
+            relate_statement_parser(stmt.clone()).map(Statement::Relate),
             return_statement_parser(stmt.clone()).map(Statement::Return),
             define_statement_parser(stmt.clone()).map(Statement::Define),
             select_statement_parser(stmt.clone()).map(Statement::Select),
-            let_statement_parser(stmt).map(Statement::Let),
+            let_statement_parser(stmt.clone()).map(Statement::Let),
+            if_else_parser(expr_parser(stmt.clone()), stmt.clone()).map(Statement::IfElse),
+            for_statement_parser(stmt.clone()).map(Statement::For),
+            just(Token::Keyword(Keyword::Break)).to(Statement::Break),
+            just(Token::Keyword(Keyword::Continue)).to(Statement::Continue),
+            throw_statement_parser(stmt).map(Statement::Throw),
         ))
         .recover_with(via_parser(invalid_statement_parser()));
         statement.map_with(|s, span| (s, span.span()))
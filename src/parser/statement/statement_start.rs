@@ -15,9 +15,12 @@ pub fn statement_start_parser<'tokens, 'src: 'tokens>(
         end().ignored(),
         just(Token::Keyword(Keyword::Create)).ignored(),
         just(Token::Keyword(Keyword::Update)).ignored(),
+        just(Token::Keyword(Keyword::Upsert)).ignored(),
         just(Token::Keyword(Keyword::Delete)).ignored(),
+        just(Token::Keyword(Keyword::Insert)).ignored(),
         just(Token::Keyword(Keyword::Select)).ignored(),
         just(Token::Keyword(Keyword::Define)).ignored(),
         just(Token::Keyword(Keyword::Let)).ignored(),
+        just(Token::Keyword(Keyword::Relate)).ignored(),
     ))
 }
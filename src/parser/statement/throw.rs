@@ -0,0 +1,20 @@
+use chumsky::{primitive::just, Parser};
+
+use crate::{
+    ast::{expr::parser::Expression, parser::Extra, statement::statement::Statement},
+    lexer::{keyword::Keyword, token::Token},
+    parser::expr::{newline::optional_new_line, parser::expr_parser},
+    util::span::{ParserInput, Spanned},
+};
+
+pub fn throw_statement_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    just(Token::Keyword(Keyword::Throw))
+        .ignore_then(optional_new_line())
+        .ignore_then(expr_parser(stmt))
+}
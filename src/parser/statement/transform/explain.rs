@@ -0,0 +1,15 @@
+use chumsky::{primitive::just, Parser};
+
+use crate::{
+    ast::{parser::Extra, statement::transform::Transform},
+    lexer::{keyword::Keyword, token::Token},
+    parser::keyword::word,
+    util::span::ParserInput,
+};
+
+pub fn explain_parser<'tokens, 'src: 'tokens>(
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    word("explain")
+        .then(just(Token::Keyword(Keyword::Full)).or_not())
+        .map(|_| Transform::Explain)
+}
@@ -0,0 +1,23 @@
+use chumsky::Parser;
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{statement::Statement, transform::Transform},
+    },
+    parser::{expr::newline::optional_new_line, keyword::word},
+    util::span::{ParserInput, Spanned},
+};
+
+use super::fields_parser;
+
+pub fn fetch_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    word("fetch")
+        .ignore_then(optional_new_line())
+        .ignore_then(fields_parser(stmt))
+        .map(Transform::Fetch)
+}
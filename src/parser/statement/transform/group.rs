@@ -0,0 +1,28 @@
+use chumsky::{
+    primitive::{choice, just},
+    Parser,
+};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{statement::Statement, transform::Transform},
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{expr::newline::optional_new_line, keyword::word},
+    util::span::{ParserInput, Spanned},
+};
+
+use super::fields_parser;
+
+pub fn group_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    just(Token::Keyword(Keyword::Group))
+        .then(just(Token::Keyword(Keyword::By)).or_not())
+        .ignore_then(optional_new_line())
+        .ignore_then(choice((word("all").map(|_| vec![]), fields_parser(stmt))))
+        .map(Transform::Group)
+}
@@ -19,6 +19,7 @@ pub fn limit_parser<'tokens, 'src: 'tokens>(
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
     just(Token::Keyword(Keyword::Limit))
+        .then(just(Token::Keyword(Keyword::By)).or_not())
         .ignore_then(optional_new_line().ignore_then(expr_parser(stmt)))
         .map(|x| Transform::Limit(Some(x)))
         .recover_with(via_parser(
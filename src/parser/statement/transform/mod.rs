@@ -1,22 +1,31 @@
 use chumsky::{
-    primitive::{any, choice, one_of},
+    primitive::{any, choice, just, one_of},
     recovery::skip_then_retry_until,
-    select, Parser,
+    select, IterParser, Parser,
 };
 
 use crate::{
     ast::{
+        expr::parser::Expression,
         parser::Extra,
         statement::{statement::Statement, transform::Transform},
     },
     lexer::{keyword::Keyword, token::Token},
-    parser::expr::newline::optional_new_line,
+    parser::expr::{newline::optional_new_line, parser::expr_parser},
     util::span::{ParserInput, Spanned},
 };
 
+pub mod explain;
+pub mod fetch;
+pub mod group;
 pub mod limit;
+pub mod order;
+pub mod parallel;
 pub mod skip;
+pub mod split;
+pub mod timeout;
 pub mod where_;
+pub mod with;
 
 pub fn transform_parser<'tokens, 'src: 'tokens>(
     stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
@@ -26,23 +35,70 @@ pub fn transform_parser<'tokens, 'src: 'tokens>(
     choice((
         where_::where_parser(stmt.clone()),
         limit::limit_parser(stmt.clone()),
-        skip::skip_parser(stmt),
+        skip::skip_parser(stmt.clone()),
+        timeout::timeout_parser(stmt),
+        parallel::parallel_parser(),
     ))
-    .recover_with(skip_then_retry_until(
-        any().ignored(),
-        one_of(vec![
-            Token::Keyword(Keyword::Where),
-            Token::Keyword(Keyword::Limit),
-            Token::Keyword(Keyword::Skip),
-            Token::Keyword(Keyword::Create),
-            Token::Keyword(Keyword::Update),
-            Token::Keyword(Keyword::Delete),
-            Token::Punctuation(';'),
-            Token::Newline,
-        ])
-        .ignored(),
+    .recover_with(skip_then_retry_until(any().ignored(), transform_end()))
+}
+
+/// The clauses of a `SELECT` statement which follow its targets.
+pub fn select_transform_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    choice((
+        with::with_parser(),
+        where_::where_parser(stmt.clone()),
+        split::split_parser(stmt.clone()),
+        group::group_parser(stmt.clone()),
+        order::order_parser(stmt.clone()),
+        limit::limit_parser(stmt.clone()),
+        skip::skip_parser(stmt.clone()),
+        fetch::fetch_parser(stmt.clone()),
+        timeout::timeout_parser(stmt),
+        parallel::parallel_parser(),
+        explain::explain_parser(),
     ))
-    // .recover_with(via_parser(invalid_transform_parser()))
+    .recover_with(skip_then_retry_until(any().ignored(), transform_end()))
+}
+
+/// Where skipping over an invalid clause stops.
+fn transform_end<'tokens, 'src: 'tokens>(
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, (), Extra<'tokens>> + Clone + 'tokens {
+    one_of(vec![
+        Token::Keyword(Keyword::Where),
+        Token::Keyword(Keyword::Group),
+        Token::Keyword(Keyword::Order),
+        Token::Keyword(Keyword::Limit),
+        Token::Keyword(Keyword::Skip),
+        Token::Keyword(Keyword::Select),
+        Token::Keyword(Keyword::Create),
+        Token::Keyword(Keyword::Update),
+        Token::Keyword(Keyword::Upsert),
+        Token::Keyword(Keyword::Delete),
+        Token::Keyword(Keyword::Insert),
+        Token::Keyword(Keyword::Relate),
+        Token::Punctuation(';'),
+        Token::Newline,
+    ])
+    .ignored()
+}
+
+/// A comma separated list of fields, as taken by `SPLIT`, `GROUP` and
+/// `FETCH`.
+fn fields_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Vec<Spanned<Expression>>, Extra<'tokens>>
+       + Clone
+       + 'tokens {
+    expr_parser(stmt)
+        .separated_by(just(Token::Punctuation(',')).then(optional_new_line()))
+        .at_least(1)
+        .collect::<Vec<_>>()
 }
 
 pub fn unexpected_parser<'tokens, 'src: 'tokens>(
@@ -0,0 +1,40 @@
+use chumsky::{
+    primitive::{choice, just},
+    IterParser, Parser,
+};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{statement::Statement, transform::Transform},
+    },
+    lexer::{keyword::Keyword, token::Token},
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+pub fn order_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    // How the values are compared doesn't change the records, so the
+    // modifiers are only checked by the parser.
+    let ordering = expr_parser(stmt)
+        .then_ignore(word("collate").or_not())
+        .then_ignore(word("numeric").or_not())
+        .then_ignore(choice((word("asc"), word("desc"))).or_not());
+    just(Token::Keyword(Keyword::Order))
+        .then(just(Token::Keyword(Keyword::By)).or_not())
+        .ignore_then(optional_new_line())
+        .ignore_then(
+            ordering
+                .separated_by(just(Token::Punctuation(',')).then(optional_new_line()))
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(Transform::Order)
+}
@@ -0,0 +1,12 @@
+use chumsky::Parser;
+
+use crate::{
+    ast::{parser::Extra, statement::transform::Transform},
+    parser::keyword::word,
+    util::span::ParserInput,
+};
+
+pub fn parallel_parser<'tokens, 'src: 'tokens>(
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    word("parallel").map(|_| Transform::Parallel)
+}
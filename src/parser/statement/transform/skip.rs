@@ -1,4 +1,8 @@
-use chumsky::{primitive::just, recovery::via_parser, Parser};
+use chumsky::{
+    primitive::{choice, just},
+    recovery::via_parser,
+    Parser,
+};
 
 use crate::{
     ast::{
@@ -8,6 +12,7 @@ use crate::{
     lexer::{keyword::Keyword, token::Token},
     parser::{
         expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
         statement::statement::statement_parser,
     },
     util::span::{ParserInput, Spanned},
@@ -18,10 +23,12 @@ pub fn skip_parser<'tokens, 'src: 'tokens>(
         + Clone
         + 'tokens,
 ) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
-    just(Token::Keyword(Keyword::Skip))
+    let skip = choice((
+        just(Token::Keyword(Keyword::Skip)).ignored(),
+        word("start").then(word("at").or_not()).ignored(),
+    ));
+    skip.clone()
         .ignore_then(optional_new_line().ignore_then(expr_parser(stmt)))
         .map(|x| Transform::Skip(Some(x)))
-        .recover_with(via_parser(
-            just(Token::Keyword(Keyword::Skip)).map(|_| Transform::Skip(None)),
-        ))
+        .recover_with(via_parser(skip.map(|_| Transform::Skip(None))))
 }
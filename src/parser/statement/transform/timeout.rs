@@ -0,0 +1,26 @@
+use chumsky::{recovery::via_parser, Parser};
+
+use crate::{
+    ast::{
+        parser::Extra,
+        statement::{statement::Statement, transform::Transform},
+    },
+    parser::{
+        expr::{newline::optional_new_line, parser::expr_parser},
+        keyword::word,
+    },
+    util::span::{ParserInput, Spanned},
+};
+
+pub fn timeout_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    word("timeout")
+        .ignore_then(optional_new_line().ignore_then(expr_parser(stmt)))
+        .map(|x| Transform::Timeout(Some(x)))
+        .recover_with(via_parser(
+            word("timeout").map(|_| Transform::Timeout(None)),
+        ))
+}
@@ -0,0 +1,28 @@
+use chumsky::{
+    primitive::{choice, just},
+    select, IterParser, Parser,
+};
+
+use crate::{
+    ast::{parser::Extra, statement::transform::Transform},
+    lexer::token::Token,
+    parser::{expr::newline::optional_new_line, keyword::word},
+    util::span::ParserInput,
+};
+
+pub fn with_parser<'tokens, 'src: 'tokens>(
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Transform, Extra<'tokens>> + Clone + 'tokens {
+    let ident = select! {
+        Token::Identifier(s) => s,
+    };
+    let index = word("index").ignore_then(optional_new_line()).ignore_then(
+        ident
+            .separated_by(just(Token::Punctuation(',')).then(optional_new_line()))
+            .at_least(1)
+            .collect::<Vec<_>>(),
+    );
+    word("with")
+        .ignore_then(optional_new_line())
+        .ignore_then(choice((word("noindex"), index.ignored())))
+        .map(|_| Transform::With)
+}
@@ -0,0 +1,126 @@
+use chumsky::{
+    primitive::{choice, just},
+    select, Parser,
+};
+
+use crate::{
+    ast::{
+        expr::parser::Expression,
+        parser::Extra,
+        statement::statement::Statement,
+        target::{RecordKey, Target},
+    },
+    lexer::token::Token,
+    util::span::{ParserInput, Spanned},
+};
+
+use super::expr::{array::array_parser, object::object_parser, parser::expr_parser};
+
+pub fn target_parser<'tokens, 'src: 'tokens>(
+    stmt: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Statement>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Target>, Extra<'tokens>> + Clone + 'tokens
+{
+    let ident = select! {
+        Token::Identifier(s) => s,
+    }
+    .map_with(|x, s| (x, s.span()));
+    let expr = expr_parser(stmt);
+
+    choice((
+        record_id_parser(expr.clone()),
+        ident.map(Target::Table),
+        expr.map(Target::Expression),
+    ))
+    .map_with(|t, s| (t, s.span()))
+}
+
+/// A record id such as `person:tobie` or `person:[1, 2]`, or a range of them
+/// such as `person:1..100`.
+pub fn record_id_parser<'tokens, 'src: 'tokens>(
+    expr: impl Parser<'tokens, ParserInput<'tokens, 'src>, Spanned<Expression>, Extra<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, ParserInput<'tokens, 'src>, Target, Extra<'tokens>> + Clone + 'tokens {
+    let ident = select! {
+        Token::Identifier(s) => s,
+    }
+    .map_with(|x, s| (x, s.span()));
+
+    let id = select! {
+        Token::Identifier(s) => RecordKey::Id(s),
+        Token::Integer(s) => RecordKey::Id(s),
+    };
+    let value =
+        choice((array_parser(expr.clone()), object_parser(expr.clone()))).map(RecordKey::Value);
+    let key = choice((id, value)).map_with(|k, s| (k, s.span()));
+    let table = ident.then_ignore(just(Token::Punctuation(':')));
+
+    // `..=` includes the end of the range, which doesn't change what the
+    // records look like.
+    let dots = just(Token::Punctuation('.'))
+        .then(just(Token::Punctuation('.')))
+        .then(just(Token::Operator("=".to_string())).or_not());
+    let range = table
+        .clone()
+        .then(key.clone().or_not())
+        .then_ignore(dots)
+        .then(key.clone().or_not())
+        .map(|((table, start), end)| Target::Range(table, start, end));
+    let record = table
+        .then(key)
+        .map(|(table, key)| Target::Record(table, key));
+
+    choice((range, record))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        declarations::type_::Type,
+        util::testing::{array, get_diagnostics, get_type, object, scope, SCHEMA},
+    };
+
+    #[test]
+    fn targets_are_scoped_to_their_table() {
+        let scope = scope(SCHEMA);
+        let people = array(object(&[
+            ("id", Type::Record("person".to_string())),
+            ("name", Type::String),
+        ]));
+        for target in [
+            "person",
+            "person:tobie",
+            "person:⟨complex id⟩",
+            "person:[1, 'a']",
+            "person:{ a: 1 }",
+            "person:1..100",
+            "person:..=100",
+            "(SELECT * FROM person)",
+        ] {
+            let src = format!("SELECT id, name FROM {target}");
+            assert_eq!(get_diagnostics(&src, &scope), Vec::<String>::new(), "{src}");
+            assert_eq!(get_type(&src, &scope), people, "{src}");
+        }
+        assert_eq!(
+            get_type(
+                "LET $p = SELECT * FROM person; SELECT id, name FROM $p",
+                &scope
+            ),
+            people
+        );
+        assert_eq!(
+            get_diagnostics("UPDATE person:tobie SET nope = 1", &scope),
+            vec!["Field nope does not exist"]
+        );
+        assert_eq!(
+            get_diagnostics("DELETE post:1 WHERE name = 'a'", &scope),
+            vec!["Unknown field 'name'"]
+        );
+        assert_eq!(
+            get_diagnostics("SELECT * FROM nope:1", &scope),
+            vec!["Table 'nope' not found"]
+        );
+    }
+}
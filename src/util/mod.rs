@@ -1,3 +1,5 @@
 pub mod offset;
 pub mod range;
 pub mod span;
+#[cfg(test)]
+pub mod testing;
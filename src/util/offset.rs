@@ -1,9 +1,12 @@
 use ropey::Rope;
 use tower_lsp::lsp_types::Position;
 
+/// The position of the byte `offset`, as spans are byte offsets into the
+/// text. Columns count UTF-16 code units, as LSP clients do by default.
 pub fn offset_to_position(offset: usize, rope: &Rope) -> Option<Position> {
-    let line = rope.try_char_to_line(offset).ok()?;
+    let line = rope.try_byte_to_line(offset).ok()?;
+    let char = rope.try_byte_to_char(offset).ok()?;
     let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    let column = offset - first_char_of_line;
+    let column = rope.char_to_utf16_cu(char) - rope.char_to_utf16_cu(first_char_of_line);
     Some(Position::new(line as u32, column as u32))
 }
@@ -0,0 +1,121 @@
+//! Helpers shared by the unit tests.
+
+use ropey::Rope;
+use tower_lsp::lsp_types::Position;
+
+use crate::{
+    ast::{
+        expr::types::Typed,
+        parser::File,
+        statement::{let_::LetStatement, statement::Statement},
+    },
+    declarations::{field::Field, object::Object, scoped_item::ScopedItems, type_::Type},
+    features::diagnostics::diagnostic::parse_file,
+    ls::workspace::{get_table_defs, parse_document},
+};
+
+pub fn parse(src: &str) -> File {
+    parse_document(&Rope::from_str(src)).expect("the source should parse")
+}
+
+/// The tables most tests are run against.
+pub const SCHEMA: &str = "
+DEFINE TABLE person;
+DEFINE FIELD name ON person TYPE string;
+DEFINE FIELD age ON person TYPE int;
+DEFINE FIELD friend ON person TYPE option<record<person>>;
+DEFINE TABLE post;
+DEFINE FIELD title ON post TYPE string;
+DEFINE TABLE likes;
+DEFINE FIELD in ON likes TYPE record<person>;
+DEFINE FIELD out ON likes TYPE record<post>;
+";
+
+/// A scope with the tables defined by the `DEFINE` statements in `schema`.
+pub fn scope(schema: &str) -> ScopedItems {
+    ScopedItems {
+        table_definitions: get_table_defs([parse(schema)]),
+        ..Default::default()
+    }
+}
+
+/// The type of every statement in `src`, with the variables declared by
+/// `LET` in scope for the statements after them.
+pub fn get_types(src: &str, scope: &ScopedItems) -> Vec<Type> {
+    let mut scope = scope.clone();
+    let mut types = vec![];
+    for (statement, _) in parse(src) {
+        if let Statement::Let(LetStatement {
+            name: Some(name),
+            value: Some(value),
+        }) = &statement
+        {
+            let ty = value.0.get_type(&scope);
+            scope.variables.insert(name.0.clone(), ty);
+        }
+        types.push(statement.get_type(&scope));
+    }
+    types
+}
+
+/// The type of the last statement in `src`.
+pub fn get_type(src: &str, scope: &ScopedItems) -> Type {
+    get_types(src, scope)
+        .pop()
+        .expect("the source should not be empty")
+}
+
+/// The messages of the diagnostics reported for `src`.
+pub fn get_diagnostics(src: &str, scope: &ScopedItems) -> Vec<String> {
+    let mut scope = scope.clone();
+    parse_file(src.to_string(), &Rope::from_str(src), &mut scope)
+        .1
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect()
+}
+
+/// The text each diagnostic reported for `src` covers, with its message.
+pub fn get_located_diagnostics(src: &str, scope: &ScopedItems) -> Vec<(String, String)> {
+    let rope = Rope::from_str(src);
+    let to_char = |position: Position| {
+        let line_start = rope.char_to_utf16_cu(rope.line_to_char(position.line as usize));
+        rope.utf16_cu_to_char(line_start + position.character as usize)
+    };
+    let mut scope = scope.clone();
+    parse_file(src.to_string(), &rope, &mut scope)
+        .1
+        .into_iter()
+        .map(|diagnostic| {
+            let start = to_char(diagnostic.range.start);
+            let end = to_char(diagnostic.range.end);
+            (rope.slice(start..end).to_string(), diagnostic.message)
+        })
+        .collect()
+}
+
+/// An object type with `fields`, where the optional ones aren't required.
+pub fn object(fields: &[(&str, Type)]) -> Type {
+    Type::Object(Object {
+        fields: fields
+            .iter()
+            .map(|(name, ty)| Field {
+                name: name.to_string(),
+                ty: ty.clone(),
+                is_required: !matches!(ty, Type::Option(_)),
+                location: None,
+                owner: None,
+            })
+            .collect(),
+        location: None,
+        is_flexible: false,
+    })
+}
+
+pub fn array(ty: Type) -> Type {
+    Type::Array(Box::new(ty))
+}
+
+pub fn option(ty: Type) -> Type {
+    Type::Option(Box::new(ty))
+}